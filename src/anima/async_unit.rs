@@ -0,0 +1,1058 @@
+use super::diff::{self, Change};
+use super::metamorphic::Relation;
+use super::report::Report;
+use super::reporter::{Assertion, Reporter, Status};
+use super::shrink::Shrink;
+use super::soul::Testing;
+use super::tree::{GroupKind, Tree};
+use super::unit::Unit;
+use fake::{Dummy, Faker};
+use rand::rngs::StdRng;
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::mem;
+use std::pin::{Pin, pin};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+use tokio::runtime::{Builder, EnterGuard, Handle, Runtime, RuntimeFlavor};
+use tokio::task;
+use tokio::time::timeout;
+
+/// The time an awaited assertion has to complete by default
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+///
+/// A unit awaiting futures on a tokio runtime
+///
+/// `group_async` takes an async closure and runs it on the runtime,
+/// `subgroup_async` and the async assertions are awaited inside it. Every
+/// assertion is reported like in a `Unit`, the synchronous groups, the
+/// assertions and the configuration of `Testing` are forwarded to it and
+/// keep the chain on the async unit.
+///
+/// Created inside a multi thread runtime, like in
+/// `#[tokio::test(flavor = "multi_thread")]`, the unit runs its groups on
+/// that runtime. A current thread runtime, like in `#[tokio::test]`, can't
+/// drive a group while it blocks, so the unit owns a multi thread runtime
+/// whose worker threads drive the timers and the IO of its groups.
+///
+pub struct AsyncUnit {
+    unit: Unit,
+    runtime: Driver,
+    timeout: Duration,
+}
+
+/// The runtime running the groups
+enum Driver {
+    Owned(Arc<Runtime>),
+    Current(Handle),
+}
+
+impl Driver {
+    /// Get the handle of the runtime
+    fn handle(&self) -> &Handle {
+        match self {
+            Self::Owned(runtime) => runtime.handle(),
+            Self::Current(handle) => handle,
+        }
+    }
+}
+
+impl Drop for AsyncUnit {
+    /// Shut an owned runtime down without blocking inside an async context
+    fn drop(&mut self) {
+        if Handle::try_current().is_err() {
+            return;
+        }
+        let handle: Handle = self.runtime.handle().clone();
+        if let Driver::Owned(runtime) = mem::replace(&mut self.runtime, Driver::Current(handle))
+            && let Ok(runtime) = Arc::try_unwrap(runtime)
+        {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl AsyncUnit {
+    ///
+    /// Create a unit sharing a runtime
+    ///
+    /// - `runtime` The runtime running the groups
+    ///
+    #[must_use]
+    pub fn with_runtime(runtime: Arc<Runtime>) -> Self {
+        Self {
+            unit: Unit::new(),
+            runtime: Driver::Owned(runtime),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    ///
+    /// Set the time an awaited assertion has to complete
+    ///
+    /// - `timeout` The default timeout
+    ///
+    pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Get the time an awaited assertion has to complete
+    #[must_use]
+    pub const fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///
+    /// Run a future to completion on the runtime, outside of any group
+    ///
+    /// Inside a multi thread runtime the worker thread is handed over to
+    /// the other tasks while blocking. Inside a current thread runtime the
+    /// future is polled on the calling thread, its timers and IO are driven
+    /// by the workers of the runtime of the unit.
+    ///
+    /// - `future` The future to run
+    ///
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        blocking(self.runtime.handle(), future)
+    }
+
+    ///
+    /// Run a group of async tests on the runtime
+    ///
+    /// - `description` The group title
+    /// - `it` The async closure that runs the group
+    ///
+    pub fn group_async<F: AsyncFnOnce(&mut Self)>(
+        &mut self,
+        description: &str,
+        it: F,
+    ) -> &mut Self {
+        self.tagged_group_async(description, &[], it)
+    }
+
+    ///
+    /// Run a group of async tests carrying tags on the runtime
+    ///
+    /// - `description` The group title
+    /// - `tags` The group tags
+    /// - `it` The async closure that runs the group
+    ///
+    pub fn tagged_group_async<F: AsyncFnOnce(&mut Self)>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        if let Some(scope) = self.unit.enter(description, GroupKind::Group, tags) {
+            let handle: Handle = self.runtime.handle().clone();
+            blocking(&handle, it(self));
+            self.unit.exit(scope);
+        }
+        self
+    }
+
+    ///
+    /// Run a subgroup of async tests inside a group
+    ///
+    /// - `description` The subgroup title
+    /// - `it` The async closure that runs the subgroup
+    ///
+    pub async fn subgroup_async<F: AsyncFnOnce(&mut Self)>(
+        &mut self,
+        description: &str,
+        it: F,
+    ) -> &mut Self {
+        self.tagged_subgroup_async(description, &[], it).await
+    }
+
+    ///
+    /// Run a subgroup of async tests carrying tags inside a group
+    ///
+    /// - `description` The subgroup title
+    /// - `tags` The subgroup tags, added to the inherited ones
+    /// - `it` The async closure that runs the subgroup
+    ///
+    pub async fn tagged_subgroup_async<F: AsyncFnOnce(&mut Self)>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        if let Some(scope) = self.unit.enter(description, GroupKind::Subgroup, tags) {
+            it(self).await;
+            self.unit.exit(scope);
+        }
+        self
+    }
+
+    ///
+    /// Check that a future resolves to the expected value
+    ///
+    /// - `description` The test description
+    /// - `future` The future to await
+    /// - `expected` The expected value
+    ///
+    pub async fn awaits<T: PartialEq + Debug, F: Future<Output = T>>(
+        &mut self,
+        description: &str,
+        future: F,
+        expected: T,
+    ) -> &mut Self {
+        let limit: Duration = self.timeout;
+        self.settle(description, limit, future, |actual| {
+            expected_value(&expected, &actual)
+        })
+        .await
+    }
+
+    ///
+    /// Check that a future resolves to `Ok` holding the expected value
+    ///
+    /// - `description` The test description
+    /// - `future` The future to await
+    /// - `expected` The expected value
+    ///
+    pub async fn resolves<T: PartialEq + Debug, E: Debug, F: Future<Output = Result<T, E>>>(
+        &mut self,
+        description: &str,
+        future: F,
+        expected: T,
+    ) -> &mut Self {
+        let limit: Duration = self.timeout;
+        self.settle(description, limit, future, |result| match result {
+            Ok(actual) => expected_value(&expected, &actual),
+            Err(e) => Some(Failure::Message(format!("rejected with {e:?}"))),
+        })
+        .await
+    }
+
+    ///
+    /// Check that a future resolves to `Err`
+    ///
+    /// - `description` The test description
+    /// - `future` The future to await
+    ///
+    pub async fn rejects<T: Debug, E, F: Future<Output = Result<T, E>>>(
+        &mut self,
+        description: &str,
+        future: F,
+    ) -> &mut Self {
+        let limit: Duration = self.timeout;
+        self.settle(description, limit, future, |result| match result {
+            Ok(value) => Some(Failure::Message(format!("resolved with {value:?}"))),
+            Err(_) => None,
+        })
+        .await
+    }
+
+    ///
+    /// Check that a future completes before the default timeout
+    ///
+    /// - `description` The test description
+    /// - `future` The future to await
+    ///
+    pub async fn completes<F: Future>(&mut self, description: &str, future: F) -> &mut Self {
+        let limit: Duration = self.timeout;
+        self.within(description, limit, future).await
+    }
+
+    ///
+    /// Check that a future completes before a timeout
+    ///
+    /// - `description` The test description
+    /// - `limit` The time the future has to complete
+    /// - `future` The future to await
+    ///
+    pub async fn within<F: Future>(
+        &mut self,
+        description: &str,
+        limit: Duration,
+        future: F,
+    ) -> &mut Self {
+        self.settle(description, limit, future, |_| None).await
+    }
+
+    ///
+    /// Await a future with a timeout and report its verdict
+    ///
+    /// The future is not polled when the test is filtered.
+    ///
+    /// - `description` The test description
+    /// - `limit` The time the future has to complete
+    /// - `future` The future to await
+    /// - `verdict` Returns the failure of the output, if any
+    ///
+    async fn settle<T, F: Future<Output = T>, V: FnOnce(T) -> Option<Failure>>(
+        &mut self,
+        description: &str,
+        limit: Duration,
+        future: F,
+        verdict: V,
+    ) -> &mut Self {
+        if !self.unit.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        let output: Option<T> = timeout(limit, future).await.ok();
+        let duration: Duration = i.elapsed();
+        let failure: Option<Failure> = output.map_or_else(
+            || {
+                Some(Failure::Message(format!(
+                    "timed out after {} ms",
+                    limit.as_millis()
+                )))
+            },
+            verdict,
+        );
+        let assertion: Assertion = match failure {
+            None => Assertion::new(description, Status::Pass, duration),
+            Some(Failure::Message(message)) => {
+                Assertion::new(description, Status::Fail, duration).with_message(&message)
+            }
+            Some(Failure::Values {
+                expected,
+                actual,
+                diff,
+            }) => Assertion::new(description, Status::Fail, duration)
+                .with_values(expected, actual)
+                .with_diff(diff),
+        };
+        self.unit.report(assertion);
+        self
+    }
+
+    ///
+    /// Run a synchronous group or subgroup between its enter and exit events
+    ///
+    /// - `description` The group title
+    /// - `kind` Group or subgroup
+    /// - `tags` The group tags
+    /// - `it` The callback that runs the group
+    ///
+    fn scope<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        kind: GroupKind,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        if let Some(scope) = self.unit.enter(description, kind, tags) {
+            it(self);
+            self.unit.exit(scope);
+        }
+        self
+    }
+}
+
+impl Default for AsyncUnit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Testing for AsyncUnit {
+    ///
+    /// Create a unit on the current multi thread runtime, or on its own one
+    /// outside of any runtime
+    ///
+    /// # Panics
+    ///
+    /// When the runtime can't be built
+    ///
+    fn new() -> Self {
+        let runtime: Driver = match Handle::try_current() {
+            Ok(current) if current.runtime_flavor().ne(&RuntimeFlavor::CurrentThread) => {
+                Driver::Current(current)
+            }
+            _ => Driver::Owned(Arc::new(
+                Builder::new_multi_thread()
+                    .enable_all()
+                    .build()
+                    .expect("failed to build the tokio runtime"),
+            )),
+        };
+        Self {
+            unit: Unit::new(),
+            runtime,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    fn ok(&mut self, description: &str, data: Vec<bool>) -> &mut Self {
+        self.unit.ok(description, data);
+        self
+    }
+
+    fn ko(&mut self, description: &str, data: Vec<bool>) -> &mut Self {
+        self.unit.ko(description, data);
+        self
+    }
+
+    fn eq<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.unit.eq(description, data, expected);
+        self
+    }
+
+    fn ne<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.unit.ne(description, data, expected);
+        self
+    }
+
+    /// Run a synchronous group, the async one is `AsyncUnit::group_async`
+    fn group<F: FnOnce(&mut Self) -> &mut Self>(&mut self, description: &str, it: F) -> &mut Self {
+        self.scope(description, GroupKind::Group, &[], it)
+    }
+
+    fn is<T: PartialEq + Debug>(&mut self, description: &str, value: T, expected: T) -> &mut Self {
+        self.unit.is(description, value, expected);
+        self
+    }
+
+    fn not<T: PartialEq + Debug>(&mut self, description: &str, value: T, expected: T) -> &mut Self {
+        self.unit.not(description, value, expected);
+        self
+    }
+
+    fn len<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.unit.len(description, data, expected);
+        self
+    }
+
+    fn full(&mut self, description: &str, min: usize, max: usize, current: usize) -> &mut Self {
+        self.unit.full(description, min, max, current);
+        self
+    }
+
+    fn gt<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.unit.gt(description, data, expected);
+        self
+    }
+
+    fn lt<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.unit.lt(description, data, expected);
+        self
+    }
+
+    fn ge<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.unit.ge(description, data, expected);
+        self
+    }
+
+    fn empty(&mut self, description: &str, data: String) -> &mut Self {
+        self.unit.empty(description, data);
+        self
+    }
+
+    fn between<T: PartialOrd>(
+        &mut self,
+        description: &str,
+        min: T,
+        max: T,
+        current: T,
+    ) -> &mut Self {
+        self.unit.between(description, min, max, current);
+        self
+    }
+
+    fn throws<E: Debug, F: FnOnce() -> Result<(), E>>(
+        &mut self,
+        description: &str,
+        f: F,
+    ) -> &mut Self {
+        self.unit.throws(description, f);
+        self
+    }
+
+    fn timed<F: FnOnce() -> bool>(&mut self, description: &str, f: F) -> &mut Self {
+        self.unit.timed(description, f);
+        self
+    }
+
+    fn bench<T, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        budget: Option<Duration>,
+        f: F,
+    ) -> &mut Self {
+        self.unit.bench(description, budget, f);
+        self
+    }
+
+    fn take(&mut self) -> Instant {
+        self.unit.take()
+    }
+
+    /// Run a synchronous subgroup, the async one is `AsyncUnit::subgroup`
+    fn subgroup<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        it: F,
+    ) -> &mut Self {
+        self.scope(description, GroupKind::Subgroup, &[], it)
+    }
+
+    /// Run a synchronous group, the async one is `AsyncUnit::tagged_group_async`
+    fn tagged_group<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        self.scope(description, GroupKind::Group, tags, it)
+    }
+
+    /// Run a synchronous subgroup, the async one is `AsyncUnit::tagged_subgroup_async`
+    fn tagged_subgroup<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        self.scope(description, GroupKind::Subgroup, tags, it)
+    }
+
+    fn get_assertions(&mut self) -> Cell<usize> {
+        self.unit.get_assertions()
+    }
+
+    fn get_failures(&mut self) -> Cell<usize> {
+        self.unit.get_failures()
+    }
+
+    fn get_skipped(&mut self) -> Cell<usize> {
+        self.unit.get_skipped()
+    }
+
+    fn get_filtered(&mut self) -> Cell<usize> {
+        self.unit.get_filtered()
+    }
+
+    fn get_records(&mut self) -> &[Assertion] {
+        self.unit.get_records()
+    }
+
+    fn get_tree(&mut self) -> &Tree {
+        self.unit.get_tree()
+    }
+
+    fn get_seed(&mut self) -> u64 {
+        self.unit.get_seed()
+    }
+
+    fn get_rng(&mut self) -> &mut StdRng {
+        self.unit.get_rng()
+    }
+
+    fn get_significance(&mut self) -> f64 {
+        self.unit.get_significance()
+    }
+
+    fn skip(&mut self, description: &str) -> &mut Self {
+        self.unit.skip(description);
+        self
+    }
+
+    fn le<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.unit.le(description, data, expected);
+        self
+    }
+
+    fn set_sleep_time(&mut self, time: u64) -> &mut Self {
+        self.unit.set_sleep_time(time);
+        self
+    }
+
+    fn set_filter(&mut self, pattern: &str) -> &mut Self {
+        self.unit.set_filter(pattern);
+        self
+    }
+
+    fn set_tags(&mut self, expression: &str) -> &mut Self {
+        self.unit.set_tags(expression);
+        self
+    }
+
+    fn set_args(&mut self, args: Vec<String>) -> &mut Self {
+        self.unit.set_args(args);
+        self
+    }
+
+    fn set_fail_fast(&mut self, fail_fast: bool) -> &mut Self {
+        self.unit.set_fail_fast(fail_fast);
+        self
+    }
+
+    fn set_significance(&mut self, significance: f64) -> &mut Self {
+        self.unit.set_significance(significance);
+        self
+    }
+
+    fn set_seed(&mut self, seed: u64) -> &mut Self {
+        self.unit.set_seed(seed);
+        self
+    }
+
+    fn add_reporter<R: Reporter + 'static>(&mut self, reporter: R) -> &mut Self {
+        self.unit.add_reporter(reporter);
+        self
+    }
+
+    fn set_reporters(&mut self, reporters: Vec<Box<dyn Reporter>>) -> &mut Self {
+        self.unit.set_reporters(reporters);
+        self
+    }
+
+    fn run(&mut self) -> ExitCode {
+        self.unit.run()
+    }
+
+    fn finish(&mut self) -> Report {
+        self.unit.finish()
+    }
+
+    fn always<T: PartialEq, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: T,
+        c: F,
+    ) -> &mut Self {
+        self.unit.always(description, iteration, expected, c);
+        self
+    }
+
+    fn confirm_contains_in<T: PartialEq, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: Vec<T>,
+        c: F,
+    ) -> &mut Self {
+        self.unit
+            .confirm_contains_in(description, iteration, expected, c);
+        self
+    }
+
+    fn confirm_not_contains_in<T: PartialEq, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: Vec<T>,
+        c: F,
+    ) -> &mut Self {
+        self.unit
+            .confirm_not_contains_in(description, iteration, expected, c);
+        self
+    }
+
+    fn deterministic<T, R, F>(&mut self, description: &str, iteration: usize, func: F) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+    {
+        self.unit.deterministic(description, iteration, func);
+        self
+    }
+
+    fn sensitive<T, R, F, M>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        func: F,
+        mutate: M,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        M: FnMut(&mut T),
+    {
+        self.unit.sensitive(description, iteration, func, mutate);
+        self
+    }
+
+    fn invariant<T, R, F, E>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        func: F,
+        equiv: E,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        E: FnMut(T) -> T,
+    {
+        self.unit.invariant(description, iteration, func, equiv);
+        self
+    }
+
+    fn metamorphic<T, R, F>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        func: F,
+        relations: Vec<Relation<'_, T, R>>,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: Debug,
+        F: FnMut(T) -> R,
+    {
+        self.unit
+            .metamorphic(description, iteration, func, relations);
+        self
+    }
+
+    fn differential<T, R, F, G>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        reference: F,
+        candidate: G,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Shrink + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        G: FnMut(T) -> R,
+    {
+        self.unit
+            .differential(description, iteration, reference, candidate);
+        self
+    }
+
+    fn eq_laws<T: Dummy<Faker> + Eq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        self.unit.eq_laws::<T>(description, iteration);
+        self
+    }
+
+    fn ord_laws<T: Dummy<Faker> + Ord + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        self.unit.ord_laws::<T>(description, iteration);
+        self
+    }
+
+    fn hash_laws<T: Dummy<Faker> + Hash + Eq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        self.unit.hash_laws::<T>(description, iteration);
+        self
+    }
+
+    fn clone_laws<T: Dummy<Faker> + PartialEq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        self.unit.clone_laws::<T>(description, iteration);
+        self
+    }
+
+    fn laws<T: Dummy<Faker> + Ord + Hash + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        self.unit.laws::<T>(description, iteration);
+        self
+    }
+
+    fn avalanche<F: FnMut(&[u8]) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        length: usize,
+        tolerance: f64,
+        hash: F,
+    ) -> &mut Self {
+        self.unit
+            .avalanche(description, iteration, length, tolerance, hash);
+        self
+    }
+
+    fn collisions<T: Dummy<Faker> + Hash + Eq, F: FnMut(&T) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        max_rate: f64,
+        hash: F,
+    ) -> &mut Self {
+        self.unit.collisions(description, iteration, max_rate, hash);
+        self
+    }
+
+    fn buckets<T: Dummy<Faker>, F: FnMut(&T) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        buckets: usize,
+        hash: F,
+    ) -> &mut Self {
+        self.unit.buckets(description, iteration, buckets, hash);
+        self
+    }
+
+    fn uniform<T: PartialEq + Debug, F: FnMut(&mut StdRng) -> T>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        categories: Vec<T>,
+        sample: F,
+    ) -> &mut Self {
+        self.unit
+            .uniform(description, iteration, categories, sample);
+        self
+    }
+
+    fn mean<F: FnMut(&mut StdRng) -> f64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: f64,
+        sample: F,
+    ) -> &mut Self {
+        self.unit.mean(description, iteration, expected, sample);
+        self
+    }
+
+    fn variance<F: FnMut(&mut StdRng) -> f64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: f64,
+        sample: F,
+    ) -> &mut Self {
+        self.unit.variance(description, iteration, expected, sample);
+        self
+    }
+
+    fn frequency<F: FnMut(&mut StdRng) -> bool>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: f64,
+        tolerance: f64,
+        sample: F,
+    ) -> &mut Self {
+        self.unit
+            .frequency(description, iteration, expected, tolerance, sample);
+        self
+    }
+
+    fn property<T: Dummy<Faker> + Shrink + Clone + Debug, F: FnMut(T) -> bool>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        property: F,
+    ) -> &mut Self {
+        self.unit.property(description, iteration, property);
+        self
+    }
+}
+
+///
+/// Block on a future, handing the worker thread over when called inside a
+/// multi thread runtime
+///
+/// A current thread runtime can't start another one on its thread, the
+/// future is then polled on it inside the context of the given runtime.
+///
+/// - `handle` The handle of the runtime running the future
+/// - `future` The future to run
+///
+fn blocking<F: Future>(handle: &Handle, future: F) -> F::Output {
+    match Handle::try_current() {
+        Ok(current) if current.runtime_flavor().eq(&RuntimeFlavor::CurrentThread) => {
+            let _context: EnterGuard<'_> = handle.enter();
+            park_on(future)
+        }
+        Ok(_) => task::block_in_place(|| handle.block_on(future)),
+        Err(_) => handle.block_on(future),
+    }
+}
+
+/// Wake a thread parked on a future
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+///
+/// Poll a future on the current thread, parking it until the future is woken
+///
+/// - `future` The future to run
+///
+fn park_on<F: Future>(future: F) -> F::Output {
+    let waker: Waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut context: Context<'_> = Context::from_waker(&waker);
+    let mut future: Pin<&mut F> = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+/// Why an awaited assertion failed
+enum Failure {
+    Message(String),
+    Values {
+        expected: String,
+        actual: String,
+        diff: Vec<Change>,
+    },
+}
+
+///
+/// Compare an awaited value with the expected one
+///
+/// - `expected` The expected value
+/// - `actual` The awaited value
+///
+fn expected_value<T: PartialEq + Debug>(expected: &T, actual: &T) -> Option<Failure> {
+    if actual.eq(expected) {
+        return None;
+    }
+    Some(Failure::Values {
+        expected: format!("{expected:?}"),
+        actual: format!("{actual:?}"),
+        diff: diff::debug(expected, actual),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::{async_unit::AsyncUnit, report::Report, soul::Testing};
+    use std::time::Duration;
+    use tokio::time::sleep;
+
+    #[test]
+    pub fn futures() {
+        let mut unit: AsyncUnit = AsyncUnit::new();
+        unit.set_reporters(Vec::new());
+        let mut runs: usize = 0;
+        let report: Report = unit
+            .group_async("network", async |u| {
+                runs += 1;
+                u.awaits("answers", async { 42 }, 42).await;
+                u.resolves("connects", async { Ok::<u8, String>(1) }, 1)
+                    .await
+                    .rejects("refuses", async { Err::<u8, &str>("refused") })
+                    .await;
+                u.subgroup_async("slow", async |u| {
+                    u.within(
+                        "hangs",
+                        Duration::from_millis(10),
+                        sleep(Duration::from_secs(5)),
+                    )
+                    .await
+                    .completes("sleeps", sleep(Duration::from_millis(1)))
+                    .await;
+                })
+                .await;
+                u.ok("sync", vec![true])
+                    .is("chained", 1, 1)
+                    .awaits("after sync", async { 2 }, 2)
+                    .await;
+            })
+            .group("sync", |u| u.ok("plain", vec![true]))
+            .finish();
+        assert_eq!(runs, 1);
+        assert_eq!(report.totals.passed, 8);
+        assert_eq!(report.totals.failed, 1);
+        assert_eq!(report.totals("network > slow").map(|c| c.failed), Some(1));
+        assert_eq!(
+            report.failures().next().and_then(|f| f.message.clone()),
+            Some("timed out after 10 ms".to_string())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    pub async fn current_runtime() {
+        let report: Report = AsyncUnit::new()
+            .set_reporters(Vec::new())
+            .group_async("inside", async |u| {
+                u.completes("sleeps", sleep(Duration::from_millis(1))).await;
+            })
+            .finish();
+        assert_eq!(report.totals.passed, 1);
+    }
+
+    #[tokio::test]
+    pub async fn current_thread() {
+        let report: Report = AsyncUnit::new()
+            .set_reporters(Vec::new())
+            .group_async("inside", async |u| {
+                u.awaits("answers", async { 42 }, 42)
+                    .await
+                    .completes("sleeps", sleep(Duration::from_millis(1)))
+                    .await
+                    .within(
+                        "hangs",
+                        Duration::from_millis(10),
+                        sleep(Duration::from_secs(5)),
+                    )
+                    .await;
+            })
+            .finish();
+        assert_eq!(report.totals.passed, 2);
+        assert_eq!(report.totals.failed, 1);
+    }
+}
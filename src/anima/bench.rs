@@ -0,0 +1,139 @@
+use super::stats;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The minimum time spent warming up before measuring
+pub const WARM_UP: Duration = Duration::from_millis(50);
+
+/// The minimum number of warm-up iterations
+pub const WARM_UP_ITERATIONS: usize = 3;
+
+/// The time spent measuring, shared between the samples
+pub const MEASUREMENT: Duration = Duration::from_millis(200);
+
+/// The time a sample should last, fast functions are batched to reach it
+const SAMPLE_TIME: Duration = Duration::from_micros(100);
+
+/// The minimum number of samples
+pub const MIN_SAMPLES: usize = 10;
+
+/// The maximum number of samples
+pub const MAX_SAMPLES: usize = 100;
+
+///
+/// The timings of a benchmarked function, in nanoseconds per iteration
+///
+/// Outliers beyond the Tukey fences, 1.5 interquartile ranges away from
+/// the quartiles, are rejected before computing the statistics.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measure {
+    pub samples: usize,
+    pub iterations: usize,
+    pub outliers: usize,
+    pub min: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Measure {
+    ///
+    /// Compute the statistics of samples
+    ///
+    /// - `samples` The nanoseconds per iteration of every sample
+    /// - `iterations` The number of iterations of a sample
+    ///
+    #[must_use]
+    pub fn from_samples(mut samples: Vec<f64>, iterations: usize) -> Self {
+        samples.sort_by(f64::total_cmp);
+        let (q1, q3): (f64, f64) = (percentile(&samples, 0.25), percentile(&samples, 0.75));
+        let fence: f64 = 1.5 * (q3 - q1);
+        let total: usize = samples.len();
+        samples.retain(|s| *s >= q1 - fence && *s <= q3 + fence);
+        let mean: f64 = stats::mean(&samples);
+        let variance: f64 = stats::variance(&samples);
+        Self {
+            samples: samples.len(),
+            iterations,
+            outliers: total - samples.len(),
+            min: samples.first().copied().unwrap_or(0.0),
+            mean,
+            median: percentile(&samples, 0.5),
+            p95: percentile(&samples, 0.95),
+            stddev: variance.sqrt(),
+        }
+    }
+
+    /// Get the median as a duration
+    #[must_use]
+    pub fn median_duration(&self) -> Duration {
+        Duration::from_secs_f64(self.median / 1e9)
+    }
+}
+
+///
+/// Benchmark a function
+///
+/// The function is warmed up, then run in samples sized from the warm-up
+/// timings: fast functions are batched and the number of samples fits the
+/// measurement time.
+///
+/// - `f` The function to benchmark, its result is kept from the optimizer
+///
+pub fn measure<T, F: FnMut() -> T>(mut f: F) -> Measure {
+    let i: Instant = Instant::now();
+    let mut warm_up: usize = 0;
+    while warm_up < WARM_UP_ITERATIONS || i.elapsed() < WARM_UP {
+        black_box(f());
+        warm_up += 1;
+    }
+    let each: f64 = i.elapsed().as_secs_f64() / warm_up as f64;
+    let iterations: usize = ((SAMPLE_TIME.as_secs_f64() / each) as usize).max(1);
+    let samples: usize = ((MEASUREMENT.as_secs_f64() / (each * iterations as f64)) as usize)
+        .clamp(MIN_SAMPLES, MAX_SAMPLES);
+    let timings: Vec<f64> = (0..samples)
+        .map(|_| {
+            let i: Instant = Instant::now();
+            for _ in 0..iterations {
+                black_box(f());
+            }
+            i.elapsed().as_nanos() as f64 / iterations as f64
+        })
+        .collect();
+    Measure::from_samples(timings, iterations)
+}
+
+///
+/// Get a percentile of sorted values by the nearest rank
+///
+/// - `sorted` The values in ascending order
+/// - `p` The percentile, between 0 and 1
+///
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank: usize = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::bench::Measure;
+
+    #[test]
+    pub fn outliers() {
+        let mut samples: Vec<f64> = (1..=19).map(f64::from).collect();
+        samples.push(1000.0);
+        let measure: Measure = Measure::from_samples(samples, 4);
+        assert_eq!(measure.outliers, 1);
+        assert_eq!(measure.samples, 19);
+        assert_eq!(measure.min, 1.0);
+        assert_eq!(measure.mean, 10.0);
+        assert_eq!(measure.median, 10.0);
+        assert_eq!(measure.p95, 19.0);
+        assert!((measure.stddev - 5.627).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,263 @@
+use std::cmp::Reverse;
+use std::fmt::Debug;
+use std::mem;
+use std::str::Chars;
+
+/// Beyond this number of compared line pairs no diff is computed, the
+/// comparisons take quadratic time but only linear memory
+const MAX_CELLS: usize = 4_000_000;
+
+/// A line of a diff between an expected and an actual value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// A line present in both values
+    Same(String),
+    /// A line only present in the expected value
+    Delete(String),
+    /// A line only present in the actual value
+    Insert(String),
+}
+
+impl Change {
+    /// Render the change with a `-`, `+` or space prefix
+    #[must_use]
+    pub fn render(&self) -> String {
+        match self {
+            Self::Same(line) => format!("  {line}"),
+            Self::Delete(line) => format!("- {line}"),
+            Self::Insert(line) => format!("+ {line}"),
+        }
+    }
+}
+
+///
+/// Compute the line diff between two texts
+///
+/// The common lines are found by Hirschberg's algorithm, which keeps two
+/// rows of the longest common subsequence table instead of the whole table.
+///
+/// - `expected` The expected text
+/// - `actual` The actual text
+///
+#[must_use]
+pub fn lines(expected: &[&str], actual: &[&str]) -> Vec<Change> {
+    let mut changes: Vec<Change> = Vec::with_capacity(expected.len().max(actual.len()));
+    align(expected, actual, &mut changes);
+    changes
+}
+
+///
+/// Append the changes turning one slice of lines into another
+///
+/// - `expected` The expected lines
+/// - `actual` The actual lines
+/// - `changes` The diff being built
+///
+fn align(expected: &[&str], actual: &[&str], changes: &mut Vec<Change>) {
+    let prefix: usize = expected
+        .iter()
+        .zip(actual)
+        .take_while(|(e, a)| e.eq(a))
+        .count();
+    changes.extend(
+        expected[..prefix]
+            .iter()
+            .map(|l| Change::Same((*l).to_string())),
+    );
+    let (expected, actual): (&[&str], &[&str]) = (&expected[prefix..], &actual[prefix..]);
+    let suffix: usize = expected
+        .iter()
+        .rev()
+        .zip(actual.iter().rev())
+        .take_while(|(e, a)| e.eq(a))
+        .count();
+    let (middle, tail): (&[&str], &[&str]) = expected.split_at(expected.len() - suffix);
+    let actual: &[&str] = &actual[..actual.len() - suffix];
+    match middle {
+        [] => changes.extend(actual.iter().map(|l| Change::Insert((*l).to_string()))),
+        [line] => match actual.iter().position(|a| a.eq(line)) {
+            Some(j) => {
+                changes.extend(actual[..j].iter().map(|l| Change::Insert((*l).to_string())));
+                changes.push(Change::Same((*line).to_string()));
+                changes.extend(
+                    actual[j + 1..]
+                        .iter()
+                        .map(|l| Change::Insert((*l).to_string())),
+                );
+            }
+            None => {
+                changes.push(Change::Delete((*line).to_string()));
+                changes.extend(actual.iter().map(|l| Change::Insert((*l).to_string())));
+            }
+        },
+        _ if actual.is_empty() => {
+            changes.extend(middle.iter().map(|l| Change::Delete((*l).to_string())));
+        }
+        _ => {
+            let half: usize = middle.len() / 2;
+            let forward: Vec<usize> = row(middle[..half].iter(), actual.iter());
+            let backward: Vec<usize> = row(middle[half..].iter().rev(), actual.iter().rev());
+            let m: usize = actual.len();
+            let split: usize = (0..=m)
+                .max_by_key(|j| (forward[*j] + backward[m - j], Reverse(*j)))
+                .unwrap_or(0);
+            align(&middle[..half], &actual[..split], changes);
+            align(&middle[half..], &actual[split..], changes);
+        }
+    }
+    changes.extend(tail.iter().map(|l| Change::Same((*l).to_string())));
+}
+
+///
+/// Compute the last row of the longest common subsequence table, with two
+/// rows of memory
+///
+/// The value at `j` is the length of the longest common subsequence of the
+/// expected lines and the first `j` actual lines.
+///
+/// - `expected` The expected lines
+/// - `actual` The actual lines
+///
+fn row<'a, E, A>(expected: E, actual: A) -> Vec<usize>
+where
+    E: Iterator<Item = &'a &'a str>,
+    A: Iterator<Item = &'a &'a str> + Clone,
+{
+    let m: usize = actual.clone().count();
+    let mut previous: Vec<usize> = vec![0; m + 1];
+    let mut current: Vec<usize> = vec![0; m + 1];
+    for e in expected {
+        for (j, a) in actual.clone().enumerate() {
+            current[j + 1] = if e.eq(a) {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+///
+/// Decode the debug representation of a string back to its raw text
+///
+/// Returns `None` when the representation is not a quoted string.
+///
+/// - `rendered` The `{:?}` representation
+///
+fn unescape(rendered: &str) -> Option<String> {
+    let quoted: &str = rendered.strip_prefix('"')?.strip_suffix('"')?;
+    let mut raw: String = String::with_capacity(quoted.len());
+    let mut chars: Chars<'_> = quoted.chars();
+    while let Some(c) = chars.next() {
+        if c.ne(&'\\') {
+            raw.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => raw.push('\n'),
+            'r' => raw.push('\r'),
+            't' => raw.push('\t'),
+            '0' => raw.push('\0'),
+            'u' => {
+                let code: String = chars.by_ref().skip(1).take_while(|c| c.ne(&'}')).collect();
+                raw.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            escaped => raw.push(escaped),
+        }
+    }
+    Some(raw)
+}
+
+///
+/// Diff the pretty debug representations of two values
+///
+/// Strings are diffed on the lines of their raw text, other values use one
+/// line per element. Returns an empty diff when both values fit on a single
+/// line.
+///
+/// - `expected` The expected value
+/// - `actual` The actual value
+///
+#[must_use]
+pub fn debug<T: Debug>(expected: &T, actual: &T) -> Vec<Change> {
+    let (left, right): (String, String) = (format!("{expected:#?}"), format!("{actual:#?}"));
+    let (left, right): (String, String) = match (unescape(&left), unescape(&right)) {
+        (Some(left), Some(right)) => (left, right),
+        _ => (left, right),
+    };
+    let (expected, actual): (Vec<&str>, Vec<&str>) =
+        (left.lines().collect(), right.lines().collect());
+    if expected.len() < 2 && actual.len() < 2 {
+        return Vec::new();
+    }
+    if expected.len().saturating_mul(actual.len()) > MAX_CELLS {
+        return Vec::new();
+    }
+    lines(&expected, &actual)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::diff::{Change, debug, lines};
+
+    #[test]
+    pub fn strings() {
+        assert_eq!(
+            debug(&"a\nb\nc", &"a\nc\nd"),
+            vec![
+                Change::Same("a".to_string()),
+                Change::Delete("b".to_string()),
+                Change::Same("c".to_string()),
+                Change::Insert("d".to_string()),
+            ]
+        );
+        assert!(debug(&"abc", &"abd").is_empty());
+        assert!(debug(&r"a\nb", &r"a\nc").is_empty());
+        assert_eq!(
+            debug(&"say \"hi\"\n\tend", &"say \"hi\"\n\tfin"),
+            vec![
+                Change::Same("say \"hi\"".to_string()),
+                Change::Delete("\tend".to_string()),
+                Change::Insert("\tfin".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn large() {
+        let expected: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+        let actual: Vec<String> = (0..1000)
+            .map(|i| {
+                if i % 7 == 0 {
+                    format!("x{i}")
+                } else {
+                    i.to_string()
+                }
+            })
+            .collect();
+        let (expected, actual): (Vec<&str>, Vec<&str>) = (
+            expected.iter().map(String::as_str).collect(),
+            actual.iter().map(String::as_str).collect(),
+        );
+        let changes: Vec<Change> = lines(&expected, &actual);
+        let count = |f: fn(&Change) -> bool| changes.iter().filter(|c| f(c)).count();
+        assert_eq!(count(|c| matches!(c, Change::Same(_))), 1000 - 143);
+        assert_eq!(count(|c| matches!(c, Change::Delete(_))), 143);
+        assert_eq!(count(|c| matches!(c, Change::Insert(_))), 143);
+    }
+
+    #[test]
+    pub fn vectors() {
+        let changes: Vec<Change> = debug(&vec![1, 2, 3], &vec![1, 3]);
+        assert_eq!(
+            changes
+                .iter()
+                .filter(|c| matches!(c, Change::Delete(_)))
+                .count(),
+            1
+        );
+        assert!(changes.contains(&Change::Delete("    2,".to_string())));
+    }
+}
@@ -0,0 +1,142 @@
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+/// A transformation of the source input, drawing from the seeded generator
+type Transform<'a, T> = Box<dyn FnMut(T, &mut StdRng) -> T + 'a>;
+
+/// A predicate between the source output and the follow-up output
+type Check<'a, R> = Box<dyn FnMut(&R, &R) -> bool + 'a>;
+
+///
+/// A named metamorphic relation between two runs of a function
+///
+/// The follow-up input is built from the source input by the composed
+/// transformations, then the relation checks the source output against the
+/// follow-up output. No oracle is needed, only the relation.
+///
+pub struct Relation<'a, T, R> {
+    name: String,
+    transforms: Vec<Transform<'a, T>>,
+    check: Check<'a, R>,
+}
+
+impl<'a, T: 'a, R: 'a> Relation<'a, T, R> {
+    ///
+    /// Create a relation checked by a predicate
+    ///
+    /// - `name` The relation name, shown in the report
+    /// - `check` Returns true when the source and follow-up outputs are related
+    ///
+    #[must_use]
+    pub fn new<C: FnMut(&R, &R) -> bool + 'a>(name: &str, check: C) -> Self {
+        Self {
+            name: name.to_string(),
+            transforms: Vec::new(),
+            check: Box::new(check),
+        }
+    }
+
+    ///
+    /// Compose a transformation after the previous ones
+    ///
+    /// - `transform` Builds the follow-up input
+    ///
+    #[must_use]
+    pub fn then<G: FnMut(T) -> T + 'a>(mut self, mut transform: G) -> Self {
+        self.transforms
+            .push(Box::new(move |input: T, _: &mut StdRng| transform(input)));
+        self
+    }
+
+    /// Get the relation name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    ///
+    /// Build the follow-up input of a source input
+    ///
+    /// - `input` The source input
+    /// - `rng` The generator of the random transformations
+    ///
+    pub(crate) fn follow(&mut self, input: T, rng: &mut StdRng) -> T {
+        self.transforms
+            .iter_mut()
+            .fold(input, |input, transform| transform(input, rng))
+    }
+
+    ///
+    /// Check the relation between two outputs
+    ///
+    /// - `source` The output of the source input
+    /// - `followup` The output of the follow-up input
+    ///
+    pub(crate) fn holds(&mut self, source: &R, followup: &R) -> bool {
+        (self.check)(source, followup)
+    }
+}
+
+impl<'a, T: 'a, R: PartialEq + 'a> Relation<'a, T, R> {
+    ///
+    /// `f(t(x)) == f(x)`
+    ///
+    /// - `name` The relation name
+    ///
+    #[must_use]
+    pub fn equal(name: &str) -> Self {
+        Self::new(name, |source: &R, followup: &R| followup.eq(source))
+    }
+
+    ///
+    /// `f(t(x)) == g(f(x))`
+    ///
+    /// - `name` The relation name
+    /// - `g` Maps the source output to the expected follow-up output
+    ///
+    #[must_use]
+    pub fn maps<G: FnMut(&R) -> R + 'a>(name: &str, mut g: G) -> Self {
+        Self::new(name, move |source: &R, followup: &R| {
+            followup.eq(&g(source))
+        })
+    }
+}
+
+impl<'a, T: 'a, R: PartialOrd + 'a> Relation<'a, T, R> {
+    ///
+    /// `f(x) <= f(t(x))`
+    ///
+    /// - `name` The relation name
+    ///
+    #[must_use]
+    pub fn increasing(name: &str) -> Self {
+        Self::new(name, |source: &R, followup: &R| source.le(followup))
+    }
+
+    ///
+    /// `f(x) >= f(t(x))`
+    ///
+    /// - `name` The relation name
+    ///
+    #[must_use]
+    pub fn decreasing(name: &str) -> Self {
+        Self::new(name, |source: &R, followup: &R| source.ge(followup))
+    }
+}
+
+impl<'a, E: 'a, R: 'a> Relation<'a, Vec<E>, R> {
+    ///
+    /// Compose a random permutation of the input
+    ///
+    /// `Relation::equal("order").shuffled()` checks that the function is
+    /// invariant under permutation.
+    ///
+    #[must_use]
+    pub fn shuffled(mut self) -> Self {
+        self.transforms
+            .push(Box::new(|mut input: Vec<E>, rng: &mut StdRng| {
+                input.shuffle(rng);
+                input
+            }));
+        self
+    }
+}
@@ -1,5 +1,35 @@
 #[doc = "The soul of all frameworks"]
 pub mod soul;
 
+#[doc = "Suite events and their outputs"]
+pub mod reporter;
+
+#[doc = "Structural diffs of failed comparisons"]
+pub mod diff;
+
+#[doc = "Hierarchical results of the groups"]
+pub mod tree;
+
+#[doc = "Result of a finished suite"]
+pub mod report;
+
+#[doc = "Selection of the tests to run"]
+pub mod select;
+
+#[doc = "Shrinking of generated inputs"]
+pub mod shrink;
+
+#[doc = "Metamorphic relations"]
+pub mod metamorphic;
+
+#[doc = "Statistical tests"]
+pub mod stats;
+
+#[doc = "Benchmark measures"]
+pub mod bench;
+
 #[doc = "Unit framework"]
 pub mod unit;
+
+#[doc = "Unit framework for async code"]
+pub mod async_unit;
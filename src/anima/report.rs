@@ -0,0 +1,135 @@
+use super::reporter::{Assertion, Status};
+use super::tree::{Counts, Group, PATH_SEPARATOR, Tree};
+use std::{
+    collections::BTreeMap,
+    process::{ExitCode, Termination},
+    time::Duration,
+};
+
+///
+/// The result of a finished suite
+///
+/// It can be returned from `main` or from a `#[test]` function, the exit
+/// code is a failure when at least one assertion failed.
+///
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub totals: Counts,
+    pub records: Vec<Assertion>,
+    pub tree: Tree,
+    pub elapsed: Duration,
+    pub seed: u64,
+}
+
+impl Report {
+    /// Check if the suite has no failures
+    #[must_use]
+    pub const fn success(&self) -> bool {
+        self.totals.failed == 0
+    }
+
+    /// Get the exit code of the suite
+    #[must_use]
+    pub fn exit_code(&self) -> ExitCode {
+        if self.success() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+
+    /// Get the failed assertions with their details
+    pub fn failures(&self) -> impl Iterator<Item = &Assertion> {
+        self.records.iter().filter(|r| r.status.eq(&Status::Fail))
+    }
+
+    ///
+    /// Get the full name of an assertion, prefixed by its group path
+    ///
+    /// - `assertion` A recorded assertion
+    ///
+    #[must_use]
+    pub fn name(&self, assertion: &Assertion) -> String {
+        assertion
+            .group
+            .and_then(|id| self.tree.get(id))
+            .map_or_else(
+                || assertion.description.clone(),
+                |group| format!("{}{PATH_SEPARATOR}{}", group.path, assertion.description),
+            )
+    }
+
+    /// Get the reasons of the skipped tests
+    pub fn skipped(&self) -> impl Iterator<Item = &str> {
+        self.records
+            .iter()
+            .filter(|r| r.status.eq(&Status::Skip))
+            .map(|r| r.description.as_str())
+    }
+
+    ///
+    /// Get a group result by its path, like `parser > numbers`
+    ///
+    /// - `path` The group path
+    ///
+    #[must_use]
+    pub fn group(&self, path: &str) -> Option<&Group> {
+        self.tree.find(path)
+    }
+
+    ///
+    /// Get the counts of a group and its descendants by its path
+    ///
+    /// - `path` The group path
+    ///
+    #[must_use]
+    pub fn totals(&self, path: &str) -> Option<Counts> {
+        self.tree.find(path).map(|g| self.tree.totals(g.id))
+    }
+
+    ///
+    /// Get the counts by tag
+    ///
+    /// A tag counts the assertions of every group carrying it, a filtered
+    /// group counts as one filtered test for its own tags only.
+    ///
+    #[must_use]
+    pub fn tags(&self) -> BTreeMap<String, Counts> {
+        let mut tags: BTreeMap<String, Counts> = BTreeMap::new();
+        for group in self.tree.groups() {
+            let filtered: usize = group
+                .children
+                .iter()
+                .filter_map(|id| self.tree.get(*id))
+                .filter(|child| child.filtered)
+                .count();
+            for tag in &group.tags {
+                let counts: &mut Counts = tags.entry(tag.clone()).or_default();
+                *counts = counts.add(group.counts);
+                counts.filtered -= filtered;
+                if group.filtered {
+                    counts.filtered += 1;
+                }
+            }
+        }
+        tags
+    }
+
+    ///
+    /// Get the assertions made in a group, descendants excluded
+    ///
+    /// - `path` The group path
+    ///
+    pub fn records(&self, path: &str) -> impl Iterator<Item = &Assertion> {
+        let id: Option<usize> = self.tree.find(path).map(|g| g.id);
+        self.records
+            .iter()
+            .filter(move |r| id.is_some() && r.group.eq(&id))
+    }
+}
+
+impl Termination for Report {
+    fn report(self) -> ExitCode {
+        self.exit_code()
+    }
+}
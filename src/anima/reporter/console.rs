@@ -0,0 +1,393 @@
+use super::{Assertion, Reporter};
+use crate::anima::diff::Change;
+use crate::anima::report::Report;
+use crate::anima::soul::{DEFAULT_WIDTH, FAILURE, SUCCESS, colored};
+use crate::anima::tree::{Counts, Group, GroupKind};
+use crossterm::{
+    cursor::{MoveLeft, MoveRight},
+    execute,
+    style::{Color, Print, ResetColor, SetForegroundColor, StyledContent, Stylize, style},
+    terminal::size,
+};
+use std::fmt::Display;
+use std::io::{Stdout, Write, stdout};
+use std::ops::Add;
+
+///
+/// Print the suite to the terminal using crossterm colors
+///
+/// Whether the lines are colored and the width they are aligned on are
+/// resolved once, when the reporter is created.
+///
+pub struct Console<W: Write = Stdout> {
+    out: W,
+    colored: bool,
+    width: u16,
+}
+
+impl Console<Stdout> {
+    ///
+    /// Create a reporter writing to the standard output
+    ///
+    /// The colors follow `NO_COLOR`, `CLICOLOR_FORCE` and whether stdout is
+    /// a terminal, the width is the terminal one or `DEFAULT_WIDTH`.
+    ///
+    #[must_use]
+    pub fn new() -> Self {
+        let width: u16 = size().map_or(DEFAULT_WIDTH, |(x, _)| x);
+        Self::with_writer(stdout(), colored(), width)
+    }
+}
+
+impl Default for Console<Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> Console<W> {
+    ///
+    /// Create a reporter writing to any output
+    ///
+    /// - `out` The output
+    /// - `colored` Print colors and cursor moves
+    /// - `width` The width the statuses are aligned on
+    ///
+    pub fn with_writer(out: W, colored: bool, width: u16) -> Self {
+        Self {
+            out,
+            colored,
+            width,
+        }
+    }
+
+    ///
+    /// Print a line ending with a status aligned on the right
+    ///
+    /// The text is printed in lowercase.
+    ///
+    /// - `symbol` The line symbol
+    /// - `text` The line text
+    /// - `status` The status between brackets
+    /// - `color` The color of the symbol and the status
+    /// - `bold` Use bold characters
+    /// - `margin` Text printed before and after the line
+    ///
+    pub(crate) fn line(
+        &mut self,
+        symbol: char,
+        text: &str,
+        status: &str,
+        color: Color,
+        bold: bool,
+        margin: (&str, &str),
+    ) {
+        let (before, after): (&str, &str) = margin;
+        let text: String = text.to_lowercase();
+        if self.colored {
+            let right: u16 = self
+                .width
+                .saturating_sub(text.chars().count().add(8) as u16);
+            let _ = execute!(
+                self.out,
+                MoveLeft(0),
+                SetForegroundColor(Color::White),
+                Print(format!(
+                    "{before}{} {}{}{}{}{}\n{after}",
+                    paint(symbol, color, bold),
+                    paint(text.as_str(), Color::White, bold),
+                    MoveRight(right),
+                    paint("[ ", Color::White, bold),
+                    paint(status, color, bold),
+                    paint(" ]", Color::White, bold),
+                )),
+                ResetColor,
+            );
+        } else {
+            let padding: usize =
+                usize::from(self.width).saturating_sub(text.chars().count().add(9));
+            let _ = writeln!(
+                self.out,
+                "{before}{symbol} {text}{} [ {status} ]{after}",
+                " ".repeat(padding)
+            );
+        }
+    }
+
+    ///
+    /// Print a success line
+    ///
+    /// - `text` The line text
+    ///
+    pub(crate) fn success(&mut self, text: &str) {
+        self.line('*', text, SUCCESS, Color::Green, true, ("", ""));
+    }
+
+    ///
+    /// Print a failure line
+    ///
+    /// - `text` The line text
+    ///
+    pub(crate) fn failure(&mut self, text: &str) {
+        self.line('!', text, FAILURE, Color::Red, false, ("", ""));
+    }
+
+    ///
+    /// Print a skipped line
+    ///
+    /// - `text` The line text
+    ///
+    pub(crate) fn skipped(&mut self, text: &str) {
+        self.line('~', text, SUCCESS, Color::Green, true, ("", ""));
+    }
+
+    ///
+    /// Print a title
+    ///
+    /// - `title` The title
+    /// - `status` The status between brackets
+    ///
+    pub(crate) fn title(&mut self, title: &str, status: &str) {
+        self.line('#', title, status, Color::Green, true, ("\n", "\n"));
+    }
+
+    ///
+    /// Print a failure detail under the failed test
+    ///
+    /// - `label` The detail name
+    /// - `value` The detail value, continuation lines are indented
+    ///
+    pub(crate) fn detail(&mut self, label: &str, value: &str) {
+        let indent: String = " ".repeat(label.len().add(5));
+        let text: String = value
+            .lines()
+            .collect::<Vec<&str>>()
+            .join(format!("\n{indent}").as_str());
+        if self.colored {
+            let _ = execute!(
+                self.out,
+                Print(format!(
+                    "  {} {}\n",
+                    paint(format!("{label} :"), Color::DarkGrey, false),
+                    paint(text, Color::White, false),
+                )),
+                ResetColor,
+            );
+        } else {
+            let _ = writeln!(self.out, "  {label} : {text}");
+        }
+    }
+
+    ///
+    /// Print a diff, deletions from the expected value in red and insertions
+    /// from the actual value in green
+    ///
+    /// - `changes` The diff to print
+    ///
+    pub(crate) fn diff(&mut self, changes: &[Change]) {
+        if self.colored {
+            let _ = writeln!(
+                self.out,
+                "  {} {}",
+                paint("- expected", Color::Red, false),
+                paint("+ actual", Color::Green, false)
+            );
+        } else {
+            let _ = writeln!(self.out, "  - expected + actual");
+        }
+        for change in changes {
+            if self.colored {
+                let color: Color = match change {
+                    Change::Same(_) => Color::DarkGrey,
+                    Change::Delete(_) => Color::Red,
+                    Change::Insert(_) => Color::Green,
+                };
+                let _ = writeln!(self.out, "  {}", paint(change.render(), color, false));
+            } else {
+                let _ = writeln!(self.out, "  {}", change.render());
+            }
+        }
+    }
+
+    ///
+    /// Close the test suite
+    ///
+    /// - `success` the failure eq zero
+    /// - `s` The sussess message
+    /// - `f` The failure message
+    /// - `stats` The suite result
+    ///
+    pub(crate) fn results(&mut self, success: bool, s: &str, f: &str, stats: &Report) {
+        if success {
+            self.line('*', s, SUCCESS, Color::Green, true, ("", "\n"));
+            self.success(format!("asserts  {}", stats.totals.passed).as_str());
+            self.success(format!("failure  {}", stats.totals.failed).as_str());
+        } else {
+            self.line('!', f, FAILURE, Color::Red, true, ("", "\n"));
+            self.failure(format!("asserts  {}", stats.totals.passed).as_str());
+            self.failure(format!("failure  {}", stats.totals.failed).as_str());
+        }
+        self.skipped(format!("skipped  {}", stats.totals.skipped).as_str());
+        self.skipped(format!("filtered {}", stats.totals.filtered).as_str());
+        self.skipped(format!("seed     {}", stats.seed).as_str());
+        if success {
+            self.title(
+                format!("execution time {}s", stats.elapsed.as_secs()).as_str(),
+                SUCCESS,
+            );
+        } else {
+            self.title(
+                format!("execution time {} ms", stats.elapsed.as_millis()).as_str(),
+                FAILURE,
+            );
+        }
+    }
+}
+
+impl<W: Write> Reporter for Console<W> {
+    fn suite_start(&mut self) {
+        self.title("starting tests", SUCCESS);
+    }
+
+    fn group_enter(&mut self, group: &Group) {
+        match group.kind {
+            GroupKind::Group => self.title(&group.description, SUCCESS),
+            GroupKind::Subgroup => self.title(&group.description, "sub"),
+        }
+    }
+
+    fn pass(&mut self, assertion: &Assertion) {
+        self.success(&assertion.description);
+        if let Some(message) = &assertion.message {
+            self.success(message);
+        }
+    }
+
+    fn fail(&mut self, assertion: &Assertion) {
+        self.failure(&assertion.description);
+        let diff: bool = !assertion.diff.is_empty();
+        for (label, value) in assertion.details() {
+            if !diff || !matches!(label, "expected" | "actual" | "diff") {
+                self.detail(format!("{label:<8}").as_str(), &value);
+            }
+        }
+        if diff {
+            self.diff(&assertion.diff);
+        }
+    }
+
+    fn skip(&mut self, assertion: &Assertion) {
+        self.skipped(&assertion.description);
+    }
+
+    fn suite_end(&mut self, report: &Report) {
+        self.title(
+            format!("Tests take {} s", report.elapsed.as_secs()).as_str(),
+            SUCCESS,
+        );
+        for group in report.tree.groups() {
+            if group.filtered {
+                let line: String = format!(
+                    "{}{}  filtered",
+                    "  ".repeat(group.depth),
+                    group.description
+                );
+                self.skipped(&line);
+                continue;
+            }
+            let totals: Counts = report.tree.totals(group.id);
+            let line: String = format!(
+                "{}{}  {} passed {} failed {} skipped {} ms",
+                "  ".repeat(group.depth),
+                group.description,
+                totals.passed,
+                totals.failed,
+                totals.skipped,
+                group.duration.as_millis()
+            );
+            if totals.failed.eq(&0) {
+                self.success(&line);
+            } else {
+                self.failure(&line);
+            }
+        }
+        for (tag, counts) in report.tags() {
+            let line: String = format!(
+                "tag {tag}  {} passed {} failed {} skipped {} filtered",
+                counts.passed, counts.failed, counts.skipped, counts.filtered
+            );
+            if counts.failed.eq(&0) {
+                self.success(&line);
+            } else {
+                self.failure(&line);
+            }
+        }
+        if !report.tree.groups().is_empty() {
+            let _ = writeln!(self.out);
+        }
+        self.results(
+            report.success(),
+            "No errors has been fouded",
+            "Errors has been founded",
+            report,
+        );
+    }
+}
+
+fn paint<D: Display>(content: D, color: Color, bold: bool) -> StyledContent<D> {
+    let styled: StyledContent<D> = style(content).with(color);
+    if bold { styled.bold() } else { styled }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::{
+        reporter::console::Console, reporter::test::Buffer, soul::Testing, soul::colors, unit::Unit,
+    };
+
+    fn render(colored: bool) -> String {
+        let buffer: Buffer = Buffer::default();
+        Unit::new()
+            .set_reporters(vec![Box::new(Console::with_writer(
+                buffer.clone(),
+                colored,
+                40,
+            ))])
+            .group("Numbers", |u| {
+                u.ok("One is One", vec![true])
+                    .eq("Lines", vec!["a\nb"], "a\nc")
+            })
+            .run();
+        buffer.contents()
+    }
+
+    #[test]
+    pub fn plain() {
+        let output: String = render(false);
+        assert!(!output.contains('\u{1b}'));
+        assert!(output.contains("\n# numbers                         [ ok ]\n"));
+        assert!(output.contains("\n* one is one                      [ ok ]\n"));
+        assert!(output.contains("\n! lines                           [ ko ]\n"));
+        assert!(output.contains("\n  - expected + actual\n    a\n  - c\n  + b\n"));
+    }
+
+    #[test]
+    pub fn colored() {
+        let output: String = render(true);
+        assert!(output.contains('\u{1b}'));
+        assert!(output.contains("numbers"));
+        assert!(output.contains("one is one"));
+        assert!(!output.contains("One is One"));
+    }
+
+    #[test]
+    pub fn modes() {
+        assert!(!colors(Some("1"), Some("1"), true));
+        assert!(colors(Some(""), None, true));
+        assert!(colors(None, Some("1"), false));
+        assert!(!colors(None, Some("0"), false));
+        assert!(!colors(None, Some(""), false));
+        assert!(colors(None, None, true));
+        assert!(!colors(None, None, false));
+    }
+}
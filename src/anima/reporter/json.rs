@@ -0,0 +1,296 @@
+use super::{Assertion, Reporter, Status};
+use crate::anima::diff::Change;
+use crate::anima::report::Report;
+use crate::anima::tree::{Group, GroupKind};
+use std::{
+    env,
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Stdout, Write, stdout},
+    path::Path,
+};
+
+/// The environment variable holding the JSON Lines output path
+pub const JSON_ENV: &str = "SOUL_JSON";
+
+///
+/// Stream the suite as JSON Lines, one object per event
+///
+/// Every line is flushed as soon as it is written so another process
+/// can follow the run live.
+///
+pub struct Json<W: Write = Stdout> {
+    out: W,
+}
+
+impl Json<Stdout> {
+    /// Create a reporter writing to the standard output
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_writer(stdout())
+    }
+}
+
+impl Default for Json<Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Json<BufWriter<File>> {
+    ///
+    /// Create a reporter writing to a file
+    ///
+    /// - `path` The output path, truncated if it exists
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created
+    ///
+    pub fn file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::with_writer(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Create a reporter writing to the path found in `SOUL_JSON`
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let path: String = env::var(JSON_ENV).ok().filter(|p| !p.is_empty())?;
+        match Self::file(&path) {
+            Ok(json) => Some(json),
+            Err(e) => {
+                eprintln!("cannot create json output {path}: {e}");
+                None
+            }
+        }
+    }
+}
+
+impl<W: Write> Json<W> {
+    ///
+    /// Create a reporter writing to any output
+    ///
+    /// - `out` The output
+    ///
+    pub fn with_writer(out: W) -> Self {
+        Self { out }
+    }
+
+    ///
+    /// Write an event and flush it
+    ///
+    /// - `event` The event name
+    /// - `fields` The already encoded fields, `"key":value` joined by commas
+    ///
+    fn event(&mut self, event: &str, fields: &str) {
+        let separator: &str = if fields.is_empty() { "" } else { "," };
+        let _ = writeln!(self.out, "{{\"event\":\"{event}\"{separator}{fields}}}");
+        let _ = self.out.flush();
+    }
+
+    fn group(&mut self, event: &str, group: &Group) {
+        let kind: &str = match group.kind {
+            GroupKind::Group => "group",
+            GroupKind::Subgroup => "subgroup",
+        };
+        let tags: Vec<String> = group.tags.iter().map(|t| string(t)).collect();
+        let counts: String = if event.eq("group_end") {
+            format!(
+                ",\"passed\":{},\"failed\":{},\"skipped\":{},\"duration_ns\":{}",
+                group.counts.passed,
+                group.counts.failed,
+                group.counts.skipped,
+                group.duration.as_nanos()
+            )
+        } else {
+            String::new()
+        };
+        self.event(
+            event,
+            format!(
+                "\"description\":{},\"path\":{},\"kind\":\"{kind}\",\"depth\":{},\"tags\":[{}]{}",
+                string(&group.description),
+                string(&group.path),
+                group.depth,
+                tags.join(","),
+                counts
+            )
+            .as_str(),
+        );
+    }
+
+    fn assertion(&mut self, assertion: &Assertion) {
+        let (event, status): (&str, &str) = match assertion.status {
+            Status::Pass => ("assertion", "pass"),
+            Status::Fail => ("assertion", "fail"),
+            Status::Skip => ("skip", "skip"),
+        };
+        let mut fields: String = format!(
+            "\"description\":{},\"status\":\"{status}\",\"duration_ns\":{}",
+            string(&assertion.description),
+            assertion.duration.as_nanos()
+        );
+        if let Some(index) = assertion.index {
+            let _ = write!(fields, ",\"index\":{index}");
+        }
+        if let Some(expected) = &assertion.expected {
+            let _ = write!(fields, ",\"expected\":{}", string(expected));
+        }
+        if let Some(actual) = &assertion.actual {
+            let _ = write!(fields, ",\"actual\":{}", string(actual));
+        }
+        if let Some(message) = &assertion.message {
+            let _ = write!(fields, ",\"message\":{}", string(message));
+        }
+        if !assertion.diff.is_empty() {
+            let diff: Vec<String> = assertion.diff.iter().map(Change::render).collect();
+            let _ = write!(fields, ",\"diff\":{}", string(&diff.join("\n")));
+        }
+        self.event(event, &fields);
+    }
+}
+
+impl<W: Write> Reporter for Json<W> {
+    fn suite_start(&mut self) {
+        self.event("suite_start", "");
+    }
+
+    fn group_enter(&mut self, group: &Group) {
+        self.group("group_start", group);
+    }
+
+    fn group_exit(&mut self, group: &Group) {
+        self.group("group_end", group);
+    }
+
+    fn pass(&mut self, assertion: &Assertion) {
+        self.assertion(assertion);
+    }
+
+    fn fail(&mut self, assertion: &Assertion) {
+        self.assertion(assertion);
+    }
+
+    fn skip(&mut self, assertion: &Assertion) {
+        self.assertion(assertion);
+    }
+
+    fn suite_end(&mut self, report: &Report) {
+        let tags: Vec<String> = report
+            .tags()
+            .iter()
+            .map(|(tag, counts)| {
+                format!(
+                    "{}:{{\"passed\":{},\"failed\":{},\"skipped\":{},\"filtered\":{}}}",
+                    string(tag),
+                    counts.passed,
+                    counts.failed,
+                    counts.skipped,
+                    counts.filtered
+                )
+            })
+            .collect();
+        self.event(
+            "suite_end",
+            format!(
+                "\"asserts\":{},\"failures\":{},\"skipped\":{},\"filtered\":{},\"elapsed_ns\":{},\"success\":{},\"seed\":{},\"tags\":{{{}}}",
+                report.totals.passed,
+                report.totals.failed,
+                report.totals.skipped,
+                report.totals.filtered,
+                report.elapsed.as_nanos(),
+                report.success(),
+                report.seed,
+                tags.join(",")
+            )
+            .as_str(),
+        );
+    }
+}
+
+///
+/// Encode a value as a JSON string
+///
+/// - `value` The value to encode
+///
+fn string(value: &str) -> String {
+    let mut encoded: String = String::with_capacity(value.len() + 2);
+    encoded.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(encoded, "\\u{:04x}", c as u32);
+            }
+            c => encoded.push(c),
+        }
+    }
+    encoded.push('"');
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::{reporter::json::Json, reporter::test::Buffer, soul::Testing, unit::Unit};
+    use serde_json::Value;
+
+    #[test]
+    pub fn events() {
+        let buffer: Buffer = Buffer::default();
+        Unit::new()
+            .set_reporters(vec![Box::new(Json::with_writer(buffer.clone()))])
+            .set_seed(3)
+            .tagged_group("say \"hi\"", &["db"], |u| {
+                u.ok("back\\slash", vec![true])
+                    .eq("two\nlines\u{1}\ttab", vec!["a\"b\nc"], "a\"b")
+                    .skip("later")
+            })
+            .run();
+        let output: String = buffer.contents();
+        let events: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).expect(line))
+            .collect();
+        let names: Vec<&str> = events.iter().filter_map(|e| e["event"].as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "suite_start",
+                "group_start",
+                "assertion",
+                "assertion",
+                "skip",
+                "group_end",
+                "suite_end"
+            ]
+        );
+        assert_eq!(events[1]["description"], "say \"hi\"");
+        assert_eq!(events[1]["kind"], "group");
+        assert_eq!(events[1]["tags"][0], "db");
+        assert_eq!(events[2]["description"], "back\\slash");
+        assert_eq!(events[2]["status"], "pass");
+        let failure: &Value = &events[3];
+        assert_eq!(failure["description"], "two\nlines\u{1}\ttab");
+        assert_eq!(failure["status"], "fail");
+        assert_eq!(failure["expected"], "\"a\\\"b\"");
+        assert_eq!(failure["actual"], "\"a\\\"b\\nc\"");
+        assert!(failure["diff"].as_str().is_some_and(|d| d.contains('\n')));
+        assert!(failure["duration_ns"].is_u64());
+        assert_eq!(events[4]["status"], "skip");
+        assert_eq!(
+            (events[5]["passed"].as_u64(), events[5]["failed"].as_u64()),
+            (Some(1), Some(1))
+        );
+        let end: &Value = &events[6];
+        assert_eq!(end["asserts"], 1);
+        assert_eq!(end["failures"], 1);
+        assert_eq!(end["skipped"], 1);
+        assert_eq!(end["filtered"], 0);
+        assert_eq!(end["success"], false);
+        assert_eq!(end["seed"], 3);
+        assert_eq!(end["tags"]["db"]["failed"], 1);
+    }
+}
@@ -0,0 +1,241 @@
+use super::{Assertion, Reporter, Status};
+use crate::anima::report::Report;
+use crate::anima::tree::Group;
+use std::{env, fmt::Write, fs, path::PathBuf, time::Duration, time::Instant};
+
+/// The environment variable holding the JUnit report path
+pub const JUNIT_ENV: &str = "SOUL_JUNIT";
+
+/// The testsuite receiving assertions made outside of any group
+const ROOT_SUITE: &str = "unit";
+
+/// A testsuite being collected
+struct Suite {
+    name: String,
+    started_at: Instant,
+    time: Duration,
+    tags: Vec<String>,
+    cases: Vec<Assertion>,
+}
+
+impl Suite {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            started_at: Instant::now(),
+            time: Duration::ZERO,
+            tags: Vec::new(),
+            cases: Vec::new(),
+        }
+    }
+
+    fn count(&self, status: Status) -> usize {
+        self.cases.iter().filter(|c| c.status.eq(&status)).count()
+    }
+}
+
+///
+/// Write a JUnit XML report when the suite ends
+///
+/// Every group and subgroup becomes a testsuite named by its path,
+/// every assertion a testcase.
+///
+pub struct Junit {
+    path: PathBuf,
+    suites: Vec<Suite>,
+    stack: Vec<usize>,
+}
+
+impl Junit {
+    ///
+    /// Create a reporter writing to a file
+    ///
+    /// - `path` The report path
+    ///
+    #[must_use]
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            suites: vec![Suite::new(ROOT_SUITE)],
+            stack: Vec::new(),
+        }
+    }
+
+    /// Create a reporter writing to the path found in `SOUL_JUNIT`
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        env::var(JUNIT_ENV)
+            .ok()
+            .filter(|path| !path.is_empty())
+            .map(Self::new)
+    }
+
+    fn current(&mut self) -> &mut Suite {
+        let index: usize = self.stack.last().copied().unwrap_or(0);
+        &mut self.suites[index]
+    }
+
+    /// Render the collected suites as XML
+    #[must_use]
+    pub fn render(&self, report: &Report) -> String {
+        let mut xml: String = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"soul\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            self.suites.iter().map(|s| s.cases.len()).sum::<usize>(),
+            self.suites
+                .iter()
+                .map(|s| s.count(Status::Fail))
+                .sum::<usize>(),
+            self.suites
+                .iter()
+                .map(|s| s.count(Status::Skip))
+                .sum::<usize>(),
+            report.elapsed.as_secs_f64()
+        );
+        for suite in self.suites.iter().filter(|s| !s.cases.is_empty()) {
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+                escape(&suite.name),
+                suite.cases.len(),
+                suite.count(Status::Fail),
+                suite.count(Status::Skip),
+                suite.time.as_secs_f64()
+            );
+            if !suite.tags.is_empty() {
+                xml.push_str("    <properties>\n");
+                for tag in &suite.tags {
+                    let _ = writeln!(
+                        xml,
+                        "      <property name=\"tag\" value=\"{}\"/>",
+                        escape(tag)
+                    );
+                }
+                xml.push_str("    </properties>\n");
+            }
+            for case in &suite.cases {
+                let _ = write!(
+                    xml,
+                    "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\"",
+                    escape(&case.description),
+                    escape(&suite.name),
+                    case.duration.as_secs_f64()
+                );
+                match case.status {
+                    Status::Pass => xml.push_str("/>\n"),
+                    Status::Skip => {
+                        let _ = writeln!(
+                            xml,
+                            ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                            escape(&case.description)
+                        );
+                    }
+                    Status::Fail => {
+                        let message: &str = case.message.as_deref().unwrap_or(&case.description);
+                        let body: String = case
+                            .details()
+                            .iter()
+                            .map(|(label, value)| format!("{label}: {value}"))
+                            .collect::<Vec<String>>()
+                            .join("\n");
+                        let _ = writeln!(
+                            xml,
+                            ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>",
+                            escape(message),
+                            escape(if body.is_empty() { message } else { &body })
+                        );
+                    }
+                }
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+impl Reporter for Junit {
+    fn group_enter(&mut self, group: &Group) {
+        let mut suite: Suite = Suite::new(&group.path);
+        suite.tags.clone_from(&group.tags);
+        self.suites.push(suite);
+        self.stack.push(self.suites.len() - 1);
+    }
+
+    fn group_exit(&mut self, _group: &Group) {
+        let suite: &mut Suite = self.current();
+        suite.time = suite.started_at.elapsed();
+        self.stack.pop();
+    }
+
+    fn pass(&mut self, assertion: &Assertion) {
+        self.current().cases.push(assertion.clone());
+    }
+
+    fn fail(&mut self, assertion: &Assertion) {
+        self.current().cases.push(assertion.clone());
+    }
+
+    fn skip(&mut self, assertion: &Assertion) {
+        self.current().cases.push(assertion.clone());
+    }
+
+    fn suite_end(&mut self, report: &Report) {
+        let root: &mut Suite = &mut self.suites[0];
+        root.time = root.started_at.elapsed();
+        if let Err(e) = fs::write(&self.path, self.render(report)) {
+            eprintln!("cannot write junit report {}: {e}", self.path.display());
+        }
+    }
+}
+
+///
+/// Escape a value for an XML attribute or text node
+///
+/// - `value` The value to escape
+///
+fn escape(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::{reporter::junit::Junit, soul::Testing, unit::Unit};
+    use std::{env::temp_dir, fs, path::PathBuf, process};
+
+    #[test]
+    pub fn report() {
+        let path: PathBuf = temp_dir().join(format!("soul-junit-{}-report.xml", process::id()));
+        Unit::new()
+            .set_reporters(vec![Box::new(Junit::new(&path))])
+            .group("numbers", |u| {
+                u.ok("one is <one>", vec![1 == 1])
+                    .subgroup("negative", |u| u.ok("minus one is one", vec![-1 == 1]))
+                    .skip("later")
+            })
+            .run();
+        let xml: String = fs::read_to_string(&path).unwrap_or_default();
+        let _ = fs::remove_file(&path);
+        assert!(xml.contains("<testsuites name=\"soul\" tests=\"3\" failures=\"1\" skipped=\"1\""));
+        assert!(
+            xml.contains("<testsuite name=\"numbers &gt; negative\" tests=\"1\" failures=\"1\"")
+        );
+        assert!(xml.contains("<testcase name=\"one is &lt;one&gt;\" classname=\"numbers\""));
+        assert!(xml.contains("<skipped message=\"later\"/>"));
+        assert!(xml.contains("<failure message=\"minus one is one\">"));
+    }
+}
@@ -0,0 +1,259 @@
+use super::diff::Change;
+use super::report::Report;
+use super::tree::Group;
+use console::Console;
+use json::Json;
+use junit::Junit;
+use std::{env, time::Duration};
+use tap::Tap;
+use terse::Terse;
+
+#[doc = "Colored terminal output"]
+pub mod console;
+
+#[doc = "JUnit XML report"]
+pub mod junit;
+
+#[doc = "Test Anything Protocol output"]
+pub mod tap;
+
+#[doc = "JSON Lines event stream"]
+pub mod json;
+
+#[doc = "libtest terse output"]
+pub mod terse;
+
+/// The environment variable selecting the terminal output format
+pub const FORMAT_ENV: &str = "SOUL_FORMAT";
+
+/// The outcome of an assertion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Skip,
+}
+
+/// An assertion as seen by the reporters
+#[derive(Debug, Clone)]
+pub struct Assertion {
+    pub description: String,
+    pub status: Status,
+    pub duration: Duration,
+    pub message: Option<String>,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub index: Option<usize>,
+    pub diff: Vec<Change>,
+    pub group: Option<usize>,
+}
+
+impl Assertion {
+    ///
+    /// Create an assertion without message
+    ///
+    /// - `description` The test description
+    /// - `status` The assertion outcome
+    /// - `duration` The time spent on the assertion
+    ///
+    #[must_use]
+    pub fn new(description: &str, status: Status, duration: Duration) -> Self {
+        Self {
+            description: description.to_string(),
+            status,
+            duration,
+            message: None,
+            expected: None,
+            actual: None,
+            index: None,
+            diff: Vec::new(),
+            group: None,
+        }
+    }
+
+    ///
+    /// Attach an additional message to the assertion
+    ///
+    /// - `message` The message to display under the assertion
+    ///
+    #[must_use]
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    ///
+    /// Attach the compared values to the assertion
+    ///
+    /// - `expected` The expected value
+    /// - `actual` The value found
+    ///
+    #[must_use]
+    pub fn with_values(mut self, expected: String, actual: String) -> Self {
+        self.expected = Some(expected);
+        self.actual = Some(actual);
+        self
+    }
+
+    ///
+    /// Attach the diff between the compared values
+    ///
+    /// - `diff` The changes from the expected to the actual value
+    ///
+    #[must_use]
+    pub fn with_diff(mut self, diff: Vec<Change>) -> Self {
+        self.diff = diff;
+        self
+    }
+
+    /// Describe the failure details, one per line
+    #[must_use]
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        let mut details: Vec<(&'static str, String)> = Vec::new();
+        if let Some(index) = self.index {
+            details.push(("index", index.to_string()));
+        }
+        if let Some(expected) = &self.expected {
+            details.push(("expected", expected.clone()));
+        }
+        if let Some(actual) = &self.actual {
+            details.push(("actual", actual.clone()));
+        }
+        if let Some(message) = &self.message {
+            details.push(("message", message.clone()));
+        }
+        if !self.diff.is_empty() {
+            let diff: Vec<String> = self.diff.iter().map(Change::render).collect();
+            details.push(("diff", diff.join("\n")));
+        }
+        details
+    }
+}
+
+///
+/// Receive the events of a test suite
+///
+/// Every method has an empty default implementation, a reporter only
+/// implements the events it cares about.
+///
+pub trait Reporter {
+    /// Called once before the first event of the suite
+    fn suite_start(&mut self) {}
+
+    ///
+    /// Called when a group or a subgroup starts
+    ///
+    /// - `group` The entered group
+    ///
+    fn group_enter(&mut self, group: &Group) {
+        let _ = group;
+    }
+
+    ///
+    /// Called when a group or a subgroup ends
+    ///
+    /// - `group` The exited group
+    ///
+    fn group_exit(&mut self, group: &Group) {
+        let _ = group;
+    }
+
+    ///
+    /// Called when an assertion succeed
+    ///
+    /// - `assertion` The assertion
+    ///
+    fn pass(&mut self, assertion: &Assertion) {
+        let _ = assertion;
+    }
+
+    ///
+    /// Called when an assertion fail
+    ///
+    /// - `assertion` The assertion
+    ///
+    fn fail(&mut self, assertion: &Assertion) {
+        let _ = assertion;
+    }
+
+    ///
+    /// Called when a test is skipped
+    ///
+    /// - `assertion` The skipped test
+    ///
+    fn skip(&mut self, assertion: &Assertion) {
+        let _ = assertion;
+    }
+
+    ///
+    /// Called once when the suite is finished
+    ///
+    /// - `report` The suite result
+    ///
+    fn suite_end(&mut self, report: &Report) {
+        let _ = report;
+    }
+}
+
+///
+/// Build the reporters selected by the environment
+///
+/// `SOUL_FORMAT` chooses the standard output format (`console`, `terse`,
+/// `tap` or `json`), `SOUL_JUNIT` adds a JUnit report and `SOUL_JSON` a
+/// JSON Lines file.
+///
+#[must_use]
+pub fn from_env() -> Vec<Box<dyn Reporter>> {
+    from_format(env::var(FORMAT_ENV).as_deref().unwrap_or_default())
+}
+
+///
+/// Build the reporters for a standard output format
+///
+/// `pretty` is an alias of `console`, unknown formats use the console.
+/// The JUnit and JSON Lines files are still selected by the environment.
+///
+/// - `format` The standard output format
+///
+#[must_use]
+pub fn from_format(format: &str) -> Vec<Box<dyn Reporter>> {
+    let mut reporters: Vec<Box<dyn Reporter>> = match format {
+        "tap" => vec![Box::new(Tap::new())],
+        "json" => vec![Box::new(Json::new())],
+        "terse" => vec![Box::new(Terse::new())],
+        _ => vec![Box::new(Console::new())],
+    };
+    if let Some(junit) = Junit::from_env() {
+        reporters.push(Box::new(junit));
+    }
+    if let Some(json) = Json::from_env() {
+        reporters.push(Box::new(json));
+    }
+    reporters
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::{cell::RefCell, io::Write, rc::Rc};
+
+    /// An output kept in memory, shared by its clones
+    #[derive(Clone, Default)]
+    pub(crate) struct Buffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Buffer {
+        /// Get everything written so far
+        pub(crate) fn contents(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap_or_default()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use super::{Assertion, Reporter};
+use crate::anima::report::Report;
+use crate::anima::tree::{Group, GroupKind};
+use std::io::{Stdout, Write, stdout};
+
+/// A TAP document, the root one or a subtest
+struct Level {
+    description: String,
+    count: usize,
+    failed: bool,
+}
+
+impl Level {
+    fn new(description: &str) -> Self {
+        Self {
+            description: description.to_string(),
+            count: 0,
+            failed: false,
+        }
+    }
+}
+
+///
+/// Print the suite using the Test Anything Protocol version 14
+///
+/// Groups become comments, subgroups become subtests and the plan is
+/// printed when the suite ends.
+///
+pub struct Tap<W: Write = Stdout> {
+    out: W,
+    levels: Vec<Level>,
+}
+
+impl Tap<Stdout> {
+    /// Create a reporter writing to the standard output
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_writer(stdout())
+    }
+}
+
+impl Default for Tap<Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> Tap<W> {
+    ///
+    /// Create a reporter writing to any output
+    ///
+    /// - `out` The output
+    ///
+    pub fn with_writer(out: W) -> Self {
+        Self {
+            out,
+            levels: vec![Level::new("")],
+        }
+    }
+
+    fn line(&mut self, line: &str) {
+        let indent: String = "    ".repeat(self.levels.len() - 1);
+        let _ = writeln!(self.out, "{indent}{line}");
+    }
+
+    ///
+    /// Print a test point in the current level
+    ///
+    /// - `ok` The test result
+    /// - `description` The test description
+    /// - `directive` An optional directive like `SKIP`
+    ///
+    fn point(&mut self, ok: bool, description: &str, directive: Option<&str>) {
+        let level: &mut Level = self.levels.last_mut().expect("root level");
+        level.count += 1;
+        level.failed |= !ok;
+        let number: usize = level.count;
+        let status: &str = if ok { "ok" } else { "not ok" };
+        let mut line: String = format!("{status} {number} - {}", escape(description));
+        if let Some(directive) = directive {
+            line.push_str(format!(" # {directive}").as_str());
+        }
+        self.line(&line);
+    }
+}
+
+impl<W: Write> Reporter for Tap<W> {
+    fn suite_start(&mut self) {
+        self.line("TAP version 14");
+    }
+
+    fn group_enter(&mut self, group: &Group) {
+        match group.kind {
+            GroupKind::Group => self.line(format!("# {}", group.description).as_str()),
+            GroupKind::Subgroup => {
+                self.levels.push(Level::new(&group.description));
+                self.line(format!("# Subtest: {}", group.description).as_str());
+            }
+        }
+    }
+
+    fn group_exit(&mut self, group: &Group) {
+        if group.kind.eq(&GroupKind::Subgroup) {
+            let plan: String = format!("1..{}", self.levels.last().map_or(0, |l| l.count));
+            self.line(&plan);
+            if let Some(level) = self.levels.pop() {
+                self.point(!level.failed, &level.description, None);
+            }
+        }
+    }
+
+    fn pass(&mut self, assertion: &Assertion) {
+        self.point(true, &assertion.description, None);
+    }
+
+    fn fail(&mut self, assertion: &Assertion) {
+        self.point(false, &assertion.description, None);
+        let details: Vec<(&str, String)> = assertion.details();
+        if !details.is_empty() {
+            self.line("  ---");
+            for (key, value) in details {
+                self.line(format!("  {key}: {}", yaml(&value)).as_str());
+            }
+            self.line("  ...");
+        }
+    }
+
+    fn skip(&mut self, assertion: &Assertion) {
+        self.point(true, &assertion.description, Some("SKIP"));
+    }
+
+    fn suite_end(&mut self, report: &Report) {
+        for (tag, counts) in report.tags() {
+            let line: String = format!(
+                "# tag {tag}: {} passed, {} failed, {} skipped, {} filtered",
+                counts.passed, counts.failed, counts.skipped, counts.filtered
+            );
+            self.line(&line);
+        }
+        let seed: String = format!("# seed {}", report.seed);
+        self.line(&seed);
+        let plan: String = format!("1..{}", self.levels[0].count);
+        self.line(&plan);
+        let _ = self.out.flush();
+    }
+}
+
+///
+/// Escape the characters having a meaning in a test point description
+///
+/// - `description` The test description
+///
+fn escape(description: &str) -> String {
+    description
+        .replace('\\', "\\\\")
+        .replace('#', "\\#")
+        .replace('\n', " ")
+}
+
+///
+/// Quote a value as a YAML double-quoted scalar
+///
+/// Control characters are escaped, so that the value stays on one line
+/// and the diagnostics remain valid YAML.
+///
+/// - `value` The value to quote
+///
+fn yaml(value: &str) -> String {
+    let mut quoted: String = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(format!("\\x{:02X}", u32::from(c)).as_str()),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::{
+        reporter::tap::{Tap, yaml},
+        reporter::test::Buffer,
+        soul::Testing,
+        unit::Unit,
+    };
+
+    #[test]
+    pub fn document() {
+        let buffer: Buffer = Buffer::default();
+        Unit::new()
+            .set_reporters(vec![Box::new(Tap::with_writer(buffer.clone()))])
+            .set_seed(7)
+            .group("numbers", |u| {
+                u.ok("one is one", vec![1 == 1])
+                    .subgroup("negative", |u| u.ok("minus one is one", vec![-1 == 1]))
+                    .skip("issue #4")
+            })
+            .run();
+        let tap: String = buffer.contents();
+        assert_eq!(
+            tap,
+            "TAP version 14\n\
+             # numbers\n\
+             ok 1 - one is one\n\
+             \x20   # Subtest: negative\n    \
+             not ok 1 - minus one is one\n    \
+             1..1\n\
+             not ok 2 - negative\n\
+             ok 3 - issue \\#4 # SKIP\n\
+             # seed 7\n\
+             1..3\n"
+        );
+    }
+
+    #[test]
+    pub fn diagnostics() {
+        let buffer: Buffer = Buffer::default();
+        Unit::new()
+            .set_reporters(vec![Box::new(Tap::with_writer(buffer.clone()))])
+            .eq("colors", vec!["\u{1b}[31mred"], "green\tlight");
+        let tap: String = buffer.contents();
+        assert!(tap.contains("\n  expected: \"\\\"green\\\\tlight\\\"\"\n"));
+        assert!(tap.contains("\n  actual: \"\\\"\\\\u{1b}[31mred\\\"\"\n"));
+        assert_eq!(yaml("\u{1b}[31m\"a\"\\\n"), "\"\\x1B[31m\\\"a\\\"\\\\\\n\"");
+    }
+}
@@ -0,0 +1,126 @@
+use super::{Assertion, Reporter};
+use crate::anima::report::Report;
+use std::io::{Stdout, Write, stdout};
+
+/// The number of results printed on a line, like libtest
+const MAX_COLUMN: usize = 88;
+
+///
+/// Print the suite like the libtest terse format
+///
+/// Every assertion prints a character, `.` for a success, `F` for a
+/// failure and `i` for a skipped test. The failures and the `test result`
+/// line are printed when the suite ends.
+///
+pub struct Terse<W: Write = Stdout> {
+    out: W,
+    column: usize,
+}
+
+impl Terse<Stdout> {
+    /// Create a reporter writing to the standard output
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_writer(stdout())
+    }
+}
+
+impl Default for Terse<Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> Terse<W> {
+    ///
+    /// Create a reporter writing to any output
+    ///
+    /// - `out` The output
+    ///
+    pub fn with_writer(out: W) -> Self {
+        Self { out, column: 0 }
+    }
+
+    fn result(&mut self, c: char) {
+        let _ = write!(self.out, "{c}");
+        self.column += 1;
+        if self.column.eq(&MAX_COLUMN) {
+            self.column = 0;
+            let _ = writeln!(self.out);
+        }
+    }
+}
+
+impl<W: Write> Reporter for Terse<W> {
+    fn suite_start(&mut self) {
+        let _ = writeln!(self.out, "\nrunning suite");
+    }
+
+    fn pass(&mut self, _assertion: &Assertion) {
+        self.result('.');
+    }
+
+    fn fail(&mut self, _assertion: &Assertion) {
+        self.result('F');
+    }
+
+    fn skip(&mut self, _assertion: &Assertion) {
+        self.result('i');
+    }
+
+    fn suite_end(&mut self, report: &Report) {
+        if self.column.ne(&0) {
+            let _ = writeln!(self.out);
+        }
+        let names: Vec<String> = report.failures().map(|f| report.name(f)).collect();
+        if !names.is_empty() {
+            let _ = writeln!(self.out, "\nfailures:");
+            for (name, failure) in names.iter().zip(report.failures()) {
+                let _ = writeln!(self.out, "\n---- {name} ----");
+                for (label, value) in failure.details() {
+                    let _ = writeln!(self.out, "{label}: {value}");
+                }
+            }
+            let _ = writeln!(self.out, "\nfailures:");
+            for name in &names {
+                let _ = writeln!(self.out, "    {name}");
+            }
+        }
+        let _ = writeln!(
+            self.out,
+            "\ntest result: {}. {} passed; {} failed; {} ignored; 0 measured; {} filtered out; finished in {:.2}s\n",
+            if report.success() { "ok" } else { "FAILED" },
+            report.totals.passed,
+            report.totals.failed,
+            report.totals.skipped,
+            report.totals.filtered,
+            report.elapsed.as_secs_f64()
+        );
+        let _ = self.out.flush();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::{reporter::terse::Terse, reporter::test::Buffer, soul::Testing, unit::Unit};
+
+    #[test]
+    pub fn results() {
+        let buffer: Buffer = Buffer::default();
+        Unit::new()
+            .set_reporters(vec![Box::new(Terse::with_writer(buffer.clone()))])
+            .group("numbers", |u| {
+                u.ok("one is one", vec![1 == 1])
+                    .ok("minus one is one", vec![-1 == 1])
+                    .skip("issue 4")
+            })
+            .run();
+        let terse: String = buffer.contents();
+        assert!(terse.starts_with("\nrunning suite\n.Fi\n\nfailures:\n"));
+        assert!(terse.contains("\n---- numbers > minus one is one ----\n"));
+        assert!(terse.contains("\nfailures:\n    numbers > minus one is one\n"));
+        assert!(terse.contains(
+            "\ntest result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out;"
+        ));
+    }
+}
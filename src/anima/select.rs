@@ -0,0 +1,233 @@
+use super::tree::PATH_SEPARATOR;
+use std::env;
+
+/// The environment variable holding the name filter
+pub const FILTER_ENV: &str = "SOUL_FILTER";
+
+/// The environment variable holding the tag selection
+pub const TAGS_ENV: &str = "SOUL_TAGS";
+
+///
+/// Select groups and assertions by name
+///
+/// A pattern containing `*` or `?` is a glob matched against the whole
+/// name, any other pattern is a substring. Names are group paths like
+/// `parser > numbers`, or `parser > numbers > description` for assertions.
+/// An exact filter must be equal to the whole name.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pattern: String,
+    exact: bool,
+}
+
+impl Filter {
+    ///
+    /// Create a filter
+    ///
+    /// - `pattern` A substring or a glob
+    ///
+    #[must_use]
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            exact: false,
+        }
+    }
+
+    ///
+    /// Create a filter matching only the exact name
+    ///
+    /// - `pattern` The full name
+    ///
+    #[must_use]
+    pub fn exact(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            exact: true,
+        }
+    }
+
+    /// Create the filter found in `SOUL_FILTER`
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        env::var(FILTER_ENV)
+            .ok()
+            .filter(|p| !p.is_empty())
+            .map(|p| Self::new(&p))
+    }
+
+    /// Get the filter pattern
+    #[must_use]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    fn is_glob(&self) -> bool {
+        !self.exact && self.pattern.contains(['*', '?'])
+    }
+
+    ///
+    /// Check if a name is selected
+    ///
+    /// - `name` The group path or assertion name
+    ///
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        if self.is_glob() {
+            let (pattern, text): (Vec<char>, Vec<char>) =
+                (self.pattern.chars().collect(), name.chars().collect());
+            glob(&pattern, &text, false)
+        } else if self.exact {
+            name.eq(self.pattern.as_str())
+        } else {
+            name.contains(self.pattern.as_str())
+        }
+    }
+
+    ///
+    /// Check if a group must run to reach selected descendants
+    ///
+    /// A substring or an exact name reaches a nested group when it starts
+    /// with the full path of its ancestors, a glob when the path is a prefix
+    /// it can match. A group that can't reach a match doesn't run.
+    ///
+    /// - `path` The group path
+    ///
+    #[must_use]
+    pub fn enters(&self, path: &str) -> bool {
+        let prefix: String = format!("{path}{PATH_SEPARATOR}");
+        if self.is_glob() {
+            let (pattern, text): (Vec<char>, Vec<char>) =
+                (self.pattern.chars().collect(), prefix.chars().collect());
+            glob(&pattern, &text, true)
+        } else {
+            self.pattern.starts_with(prefix.as_str())
+        }
+    }
+}
+
+///
+/// Select groups by tags
+///
+/// The expression is a comma separated list of tags, a tag prefixed by `!`
+/// excludes the groups carrying it. When at least one tag is included, only
+/// the groups carrying one of them run. Subgroups inherit the tags of their
+/// parents.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tags {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl Tags {
+    ///
+    /// Parse a selection expression like `!slow,db`
+    ///
+    /// - `expression` The selection expression
+    ///
+    #[must_use]
+    pub fn new(expression: &str) -> Self {
+        let mut tags: Self = Self::default();
+        for tag in expression
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+        {
+            match tag.strip_prefix('!') {
+                Some(excluded) => tags.exclude.push(excluded.trim().to_string()),
+                None => tags.include.push(tag.to_string()),
+            }
+        }
+        tags
+    }
+
+    /// Create the selection found in `SOUL_TAGS`
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        env::var(TAGS_ENV)
+            .ok()
+            .filter(|e| !e.trim().is_empty())
+            .map(|e| Self::new(&e))
+    }
+
+    ///
+    /// Check if a group carrying these tags is selected
+    ///
+    /// - `tags` The group tags, inherited ones included
+    ///
+    #[must_use]
+    pub fn selects(&self, tags: &[String]) -> bool {
+        !tags.iter().any(|t| self.exclude.contains(t))
+            && (self.include.is_empty() || tags.iter().any(|t| self.include.contains(t)))
+    }
+}
+
+///
+/// Match a text against a glob
+///
+/// - `pattern` The glob, `*` matches any sequence and `?` one character
+/// - `text` The text to match
+/// - `prefix` Accept a text ending before the pattern
+///
+fn glob(pattern: &[char], text: &[char], prefix: bool) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') => (0..=text.len()).any(|i| glob(&pattern[1..], &text[i..], prefix)),
+        Some(c) => match text.first() {
+            None => prefix,
+            Some(t) => (c.eq(&'?') || c.eq(t)) && glob(&pattern[1..], &text[1..], prefix),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::select::{Filter, Tags};
+
+    #[test]
+    pub fn substring() {
+        let filter: Filter = Filter::new("parser > numbers");
+        assert!(filter.matches("parser > numbers"));
+        assert!(filter.matches("parser > numbers > overflow"));
+        assert!(!filter.matches("parser"));
+        assert!(filter.enters("parser"));
+        assert!(!filter.enters("lexer"));
+        let nested: Filter = Filter::new("overflow");
+        assert!(!nested.matches("parser"));
+        assert!(!nested.enters("parser"));
+        assert!(nested.matches("parser > numbers > detects overflow"));
+    }
+
+    #[test]
+    pub fn glob() {
+        let filter: Filter = Filter::new("parser > *flow");
+        assert!(filter.matches("parser > overflow"));
+        assert!(!filter.matches("parser > numbers"));
+        assert!(filter.enters("parser"));
+        assert!(filter.enters("parser > numbers"));
+        assert!(!filter.enters("lexer"));
+        assert!(Filter::new("p?rser").matches("parser"));
+    }
+
+    #[test]
+    pub fn exact() {
+        let filter: Filter = Filter::exact("parser > numbers");
+        assert!(filter.matches("parser > numbers"));
+        assert!(!filter.matches("parser > numbers > overflow"));
+        assert!(filter.enters("parser"));
+        assert!(Filter::exact("p?rser").matches("p?rser"));
+        assert!(!Filter::exact("p?rser").matches("parser"));
+    }
+
+    #[test]
+    pub fn tags() {
+        let tags: Tags = Tags::new("!slow, db");
+        let of = |t: &[&str]| t.iter().map(|t| (*t).to_string()).collect::<Vec<String>>();
+        assert!(tags.selects(&of(&["db"])));
+        assert!(!tags.selects(&of(&["db", "slow"])));
+        assert!(!tags.selects(&of(&[])));
+        assert!(Tags::new("!slow").selects(&of(&[])));
+    }
+}
@@ -0,0 +1,211 @@
+/// The maximum number of accepted shrinks before the search stops
+pub const MAX_SHRINKS: usize = 1024;
+
+///
+/// Produce smaller versions of a generated input
+///
+/// The candidates are ordered from the simplest to the closest one to the
+/// value. The default implementation has no candidates, so any type can be
+/// used as a property input with an empty `impl Shrink for T {}`.
+///
+pub trait Shrink: Sized {
+    /// Get the simpler candidates of the value
+    fn shrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+///
+/// Shrink a failing input as long as a simpler candidate still fails
+///
+/// Return the minimal failing input and the number of accepted shrinks.
+///
+/// - `input` The failing input
+/// - `fails` Returns true when a candidate still fails
+///
+pub fn minimize<T: Shrink, F: FnMut(&T) -> bool>(input: T, mut fails: F) -> (T, usize) {
+    let mut current: T = input;
+    let mut shrinks: usize = 0;
+    while shrinks.lt(&MAX_SHRINKS) {
+        match current
+            .shrink()
+            .into_iter()
+            .find(|candidate| fails(candidate))
+        {
+            Some(candidate) => {
+                current = candidate;
+                shrinks += 1;
+            }
+            None => break,
+        }
+    }
+    (current, shrinks)
+}
+
+macro_rules! shrink_integer {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates: Vec<Self> = Vec::new();
+                if *self == 0 {
+                    return candidates;
+                }
+                candidates.push(0);
+                let mut distance: Self = *self / 2;
+                while distance != 0 {
+                    candidates.push(*self - distance);
+                    distance /= 2;
+                }
+                let closest: Self = if *self > 0 { *self - 1 } else { *self + 1 };
+                if !candidates.contains(&closest) {
+                    candidates.push(closest);
+                }
+                candidates
+            }
+        }
+    )*};
+}
+
+shrink_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! shrink_float {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                if *self == 0.0 {
+                    return Vec::new();
+                }
+                let mut candidates: Vec<Self> = vec![0.0];
+                if self.is_finite() {
+                    if self.trunc() != *self {
+                        candidates.push(self.trunc());
+                    }
+                    candidates.push(*self / 2.0);
+                }
+                candidates
+            }
+        }
+    )*};
+}
+
+shrink_float!(f32, f64);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self { vec![false] } else { Vec::new() }
+    }
+}
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        if self.eq(&'a') { Vec::new() } else { vec!['a'] }
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Vec<Self> {
+        let chars: Vec<char> = self.chars().collect();
+        chars
+            .shrink()
+            .into_iter()
+            .map(|c| c.into_iter().collect())
+            .collect()
+    }
+}
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates: Vec<Self> = Vec::new();
+        if self.is_empty() {
+            return candidates;
+        }
+        candidates.push(Vec::new());
+        let half: usize = self.len() / 2;
+        if half > 0 {
+            candidates.push(self[..half].to_vec());
+            candidates.push(self[half..].to_vec());
+        }
+        if self.len() > 1 {
+            for i in 0..self.len() {
+                let mut smaller: Self = self.clone();
+                smaller.remove(i);
+                candidates.push(smaller);
+            }
+        }
+        for (i, item) in self.iter().enumerate() {
+            for simpler in item.shrink() {
+                let mut candidate: Self = self.clone();
+                candidate[i] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+impl<T: Shrink> Shrink for Option<T> {
+    fn shrink(&self) -> Vec<Self> {
+        self.as_ref().map_or_else(Vec::new, |value| {
+            let mut candidates: Vec<Self> = vec![None];
+            candidates.extend(value.shrink().into_iter().map(Some));
+            candidates
+        })
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates: Vec<Self> = Vec::new();
+        candidates.extend(self.0.shrink().into_iter().map(|a| (a, self.1.clone())));
+        candidates.extend(self.1.shrink().into_iter().map(|b| (self.0.clone(), b)));
+        candidates
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates: Vec<Self> = Vec::new();
+        candidates.extend(
+            self.0
+                .shrink()
+                .into_iter()
+                .map(|a| (a, self.1.clone(), self.2.clone())),
+        );
+        candidates.extend(
+            self.1
+                .shrink()
+                .into_iter()
+                .map(|b| (self.0.clone(), b, self.2.clone())),
+        );
+        candidates.extend(
+            self.2
+                .shrink()
+                .into_iter()
+                .map(|c| (self.0.clone(), self.1.clone(), c)),
+        );
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::shrink::{Shrink, minimize};
+
+    #[test]
+    pub fn minimal() {
+        assert_eq!(minimize(5000_u32, |x| *x >= 100).0, 100);
+        assert_eq!(minimize(-73_i64, |x| *x < -10).0, -11);
+        assert_eq!(
+            minimize(String::from("hello world"), |s| s.contains('w')).0,
+            "w"
+        );
+        assert_eq!(
+            minimize(vec![3_u8, 90, 7, 200], |v| v.iter().any(|x| *x > 50)).0,
+            vec![51]
+        );
+        assert_eq!(minimize(Some(true), |o| o.is_some()).0, Some(false));
+        assert!(0_u8.shrink().is_empty());
+    }
+}
@@ -2,25 +2,99 @@ pub const SUCCESS: &str = "ok";
 pub const FAILURE: &str = "ko";
 pub const DEFAULT_SLEEP_TIME: u64 = 0;
 
-use crossterm::{
-    cursor::{MoveLeft, MoveRight},
-    execute,
-    style::{Color, Print, ResetColor, SetForegroundColor, Stylize},
-    terminal::size,
-};
+/// The width used when the terminal size is unknown
+pub const DEFAULT_WIDTH: u16 = 80;
+
 use fake::{Dummy, Fake, Faker};
+use rand::{SeedableRng, rngs::StdRng};
 
 use std::{
     cell::Cell,
+    env,
     fmt::Debug,
-    io::{Stdout, stdout},
-    ops::Add,
+    hash::Hash,
+    io::{IsTerminal, stdout},
     process::ExitCode,
     thread::sleep,
     time::{Duration, Instant},
 };
 
-use super::unit::Unit;
+use super::diff::Change;
+use super::metamorphic::Relation;
+use super::report::Report;
+use super::reporter::{Assertion, Reporter, console::Console};
+use super::shrink::Shrink;
+use super::tree::Tree;
+
+/// The environment variable holding the seed of the generated data
+pub const SEED_ENV: &str = "SOUL_SEED";
+
+///
+/// Get the seed of the generated data
+///
+/// The seed is read from `SOUL_SEED`, a random one is drawn otherwise.
+///
+/// # Panics
+///
+/// When `SOUL_SEED` is not an unsigned 64 bits integer
+///
+#[must_use]
+pub fn seed() -> u64 {
+    parse_seed(env::var(SEED_ENV).ok().as_deref())
+}
+
+///
+/// Parse the seed of the generated data
+///
+/// - `value` The value of `SOUL_SEED`, a random seed is drawn when it is
+///   unset or empty
+///
+/// # Panics
+///
+/// When the value is not an unsigned 64 bits integer
+///
+#[must_use]
+pub fn parse_seed(value: Option<&str>) -> u64 {
+    match value.map(str::trim).filter(|v| !v.is_empty()) {
+        None => rand::random(),
+        Some(seed) => seed.parse().unwrap_or_else(|_| {
+            panic!("{SEED_ENV} must be an unsigned 64 bits integer, got {seed:?}")
+        }),
+    }
+}
+
+///
+/// Check if the output must be colored
+///
+/// `NO_COLOR` disables colors, `CLICOLOR_FORCE` enables them even without
+/// terminal, otherwise colors are used only when stdout is a terminal.
+///
+#[must_use]
+pub fn colored() -> bool {
+    colors(
+        env::var("NO_COLOR").ok().as_deref(),
+        env::var("CLICOLOR_FORCE").ok().as_deref(),
+        stdout().is_terminal(),
+    )
+}
+
+///
+/// Decide if the output must be colored
+///
+/// - `no_color` The value of `NO_COLOR`, if set
+/// - `force` The value of `CLICOLOR_FORCE`, if set
+/// - `terminal` True when the output is a terminal
+///
+#[must_use]
+pub fn colors(no_color: Option<&str>, force: Option<&str>, terminal: bool) -> bool {
+    if no_color.is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+    if force.is_some_and(|v| !v.is_empty() && v.ne("0")) {
+        return true;
+    }
+    terminal
+}
 
 ///
 /// Print a skipped test message to the console
@@ -28,53 +102,193 @@ use super::unit::Unit;
 /// - `description` The test description
 ///
 pub fn skip_output(description: &str) -> bool {
-    if let Ok((x, _)) = size() {
-        let mut out: Stdout = stdout();
-        let symbol: char = '~';
-        let status: &str = SUCCESS;
-        assert!(
-            execute!(
-                out,
-                MoveLeft(0),
-                SetForegroundColor(Color::White),
-                Print(format!(
-                    "{} {}{}{}{}{}\n",
-                    symbol.green().bold(),
-                    description.to_lowercase().white().bold(),
-                    to_right(x, description),
-                    "[ ".white().bold(),
-                    status.green().bold(),
-                    " ]".white().bold()
-                )),
-                ResetColor,
-            )
-            .is_ok()
-        );
-        true
-    } else {
-        println!("~ {description}");
-        true
-    }
+    Console::new().skipped(description);
+    true
+}
+
+///
+/// Check that a function returns the same output twice for `n` inputs
+/// generated by Faker
+///
+/// The inputs are drawn from a generator seeded by `SOUL_SEED`, printed
+/// in the failure message.
+///
+/// - `n` The number of inputs
+/// - `func` The function to check
+///
+/// # Panics
+///
+/// When two calls on the same input return different outputs
+///
+pub fn assert_deterministic<T, R, F>(n: usize, func: F) -> bool
+where
+    T: Dummy<Faker> + Clone + Debug,
+    R: Eq + Debug,
+    F: FnMut(T) -> R,
+{
+    let seed: u64 = seed();
+    deterministic(
+        &mut StdRng::seed_from_u64(seed),
+        n,
+        func,
+        format!(" ({SEED_ENV}={seed})").as_str(),
+    )
+}
+
+///
+/// Check that a function returns the same output twice for `n` inputs
+/// generated by Faker
+///
+/// The inputs are drawn from the given generator, like `unit.get_rng()`,
+/// so the run is replayed with the seed of its unit.
+///
+/// - `rng` The generator of the inputs
+/// - `n` The number of inputs
+/// - `func` The function to check
+///
+/// # Panics
+///
+/// When two calls on the same input return different outputs
+///
+pub fn assert_deterministic_with_rng<T, R, F>(rng: &mut StdRng, n: usize, func: F) -> bool
+where
+    T: Dummy<Faker> + Clone + Debug,
+    R: Eq + Debug,
+    F: FnMut(T) -> R,
+{
+    deterministic(rng, n, func, "")
+}
+
+///
+/// Check that a function output changes when its input is mutated, for
+/// `n` inputs generated by Faker
+///
+/// The inputs are drawn from a generator seeded by `SOUL_SEED`, printed
+/// in the failure message.
+///
+/// - `n` The number of inputs
+/// - `func` The function to check
+/// - `mutate` Changes an input
+///
+/// # Panics
+///
+/// When a mutation leaves the output unchanged
+///
+pub fn assert_sensitive<T, R, F, M>(n: usize, func: F, mutate: M)
+where
+    T: Dummy<Faker> + Clone + Debug,
+    R: Eq + Debug,
+    F: FnMut(T) -> R,
+    M: FnMut(&mut T),
+{
+    let seed: u64 = seed();
+    sensitive(
+        &mut StdRng::seed_from_u64(seed),
+        n,
+        func,
+        mutate,
+        format!(" ({SEED_ENV}={seed})").as_str(),
+    );
+}
+
+///
+/// Check that a function output changes when its input is mutated, for
+/// `n` inputs drawn from the given generator
+///
+/// - `rng` The generator of the inputs
+/// - `n` The number of inputs
+/// - `func` The function to check
+/// - `mutate` Changes an input
+///
+/// # Panics
+///
+/// When a mutation leaves the output unchanged
+///
+pub fn assert_sensitive_with_rng<T, R, F, M>(rng: &mut StdRng, n: usize, func: F, mutate: M)
+where
+    T: Dummy<Faker> + Clone + Debug,
+    R: Eq + Debug,
+    F: FnMut(T) -> R,
+    M: FnMut(&mut T),
+{
+    sensitive(rng, n, func, mutate, "");
+}
+
+///
+/// Check that a function output is unchanged by an equivalent
+/// transformation of its input, for `n` inputs generated by Faker
+///
+/// The inputs are drawn from a generator seeded by `SOUL_SEED`, printed
+/// in the failure message.
+///
+/// - `n` The number of inputs
+/// - `func` The function to check
+/// - `equiv` Builds an equivalent input
+///
+/// # Panics
+///
+/// When an equivalent input changes the output
+///
+pub fn assert_invariant<T, R, F, E>(n: usize, func: F, equiv: E)
+where
+    T: Dummy<Faker> + Clone + Debug,
+    R: Eq + Debug,
+    F: FnMut(T) -> R,
+    E: FnMut(T) -> T,
+{
+    let seed: u64 = seed();
+    invariant(
+        &mut StdRng::seed_from_u64(seed),
+        n,
+        func,
+        equiv,
+        format!(" ({SEED_ENV}={seed})").as_str(),
+    );
+}
+
+///
+/// Check that a function output is unchanged by an equivalent
+/// transformation of its input, for `n` inputs drawn from the given
+/// generator
+///
+/// - `rng` The generator of the inputs
+/// - `n` The number of inputs
+/// - `func` The function to check
+/// - `equiv` Builds an equivalent input
+///
+/// # Panics
+///
+/// When an equivalent input changes the output
+///
+pub fn assert_invariant_with_rng<T, R, F, E>(rng: &mut StdRng, n: usize, func: F, equiv: E)
+where
+    T: Dummy<Faker> + Clone + Debug,
+    R: Eq + Debug,
+    F: FnMut(T) -> R,
+    E: FnMut(T) -> T,
+{
+    invariant(rng, n, func, equiv, "");
 }
 
-/// Vérifie que la fonction est déterministe sur `n` itérations avec un type généré via Faker.
-pub fn assert_deterministic<T, R, F>(n: usize, mut func: F) -> bool
+fn deterministic<T, R, F>(rng: &mut StdRng, n: usize, mut func: F, replay: &str) -> bool
 where
     T: Dummy<Faker> + Clone + Debug,
     R: Eq + Debug,
     F: FnMut(T) -> R,
 {
     for _ in 0..n {
-        let input: T = Faker.fake();
+        let input: T = Faker.fake_with_rng(rng);
         let out1 = func(input.clone());
         let out2 = func(input.clone());
-        assert_eq!(out1, out2, "Non-déterminisme pour l'entrée : {:?}", input);
+        assert_eq!(
+            out1, out2,
+            "not deterministic for the input {input:?}{replay}"
+        );
     }
     true
 }
 
-/// Vérifie que la fonction est sensible à une mutation de `T`.
-pub fn assert_sensitive<T, R, F, M>(n: usize, mut func: F, mut mutate: M)
+fn sensitive<T, R, F, M>(rng: &mut StdRng, n: usize, mut func: F, mut mutate: M, replay: &str)
 where
     T: Dummy<Faker> + Clone + Debug,
     R: Eq + Debug,
@@ -82,7 +296,7 @@ where
     M: FnMut(&mut T),
 {
     for _ in 0..n {
-        let mut input: T = Faker.fake();
+        let mut input: T = Faker.fake_with_rng(rng);
         let original = input.clone();
         let hash1 = func(original.clone());
 
@@ -91,14 +305,12 @@ where
 
         assert_ne!(
             hash1, hash2,
-            "Pas de changement de sortie après mutation : {:?} -> {:?}",
-            original, input
+            "the output did not change after the mutation {original:?} -> {input:?}{replay}"
         );
     }
 }
 
-/// Vérifie que la fonction est invariante sous transformation "équivalente".
-pub fn assert_invariant<T, R, F, E>(n: usize, mut func: F, mut equiv: E)
+fn invariant<T, R, F, E>(rng: &mut StdRng, n: usize, mut func: F, mut equiv: E, replay: &str)
 where
     T: Dummy<Faker> + Clone + Debug,
     R: Eq + Debug,
@@ -106,13 +318,16 @@ where
     E: FnMut(T) -> T,
 {
     for _ in 0..n {
-        let input: T = Faker.fake();
+        let input: T = Faker.fake_with_rng(rng);
         let transformed = equiv(input.clone());
 
-        let h1 = func(input);
+        let h1 = func(input.clone());
         let h2 = func(transformed);
 
-        assert_eq!(h1, h2, "La transformation équivalente a changé la sortie");
+        assert_eq!(
+            h1, h2,
+            "the equivalent transformation changed the output for the input {input:?}{replay}"
+        );
     }
 }
 
@@ -122,33 +337,8 @@ where
 /// - `description` The test description
 ///
 pub fn success_output(description: &str) -> bool {
-    if let Ok((x, _)) = size() {
-        let mut out: Stdout = stdout();
-        let status: &str = SUCCESS;
-        let symbol: char = '*';
-        assert!(
-            execute!(
-                out,
-                MoveLeft(0),
-                SetForegroundColor(Color::White),
-                Print(format!(
-                    "{} {}{}{}{}{}\n",
-                    symbol.green().bold(),
-                    description.to_lowercase().white().bold(),
-                    to_right(x, description),
-                    "[ ".white().bold(),
-                    status.green().bold(),
-                    " ]".white().bold(),
-                )),
-                ResetColor,
-            )
-            .is_ok()
-        );
-        true
-    } else {
-        println!("* {description}");
-        true
-    }
+    Console::new().success(description);
+    true
 }
 
 ///
@@ -157,34 +347,27 @@ pub fn success_output(description: &str) -> bool {
 /// - `title` The group title
 ///
 pub fn title_output(title: &str, status: &str) {
-    if let Ok((x, _)) = size() {
-        let mut out: Stdout = stdout();
-        let symbol: char = '#';
-        assert!(
-            execute!(
-                out,
-                MoveLeft(0),
-                SetForegroundColor(Color::White),
-                Print(format!(
-                    "\n{} {}{}{}{}{}\n\n",
-                    symbol.green().bold(),
-                    title.to_lowercase().white().bold(),
-                    to_right(x, title),
-                    "[ ".white().bold(),
-                    status.green().bold(),
-                    " ]".white().bold(),
-                )),
-                ResetColor,
-            )
-            .is_ok()
-        );
-    } else {
-        println!("{title}");
-    }
+    Console::new().title(title, status);
 }
 
-fn to_right(x: u16, description: &str) -> MoveRight {
-    MoveRight(x - description.len().add(8) as u16)
+///
+/// Print a failure detail under the failed test
+///
+/// - `label` The detail name
+/// - `value` The detail value, continuation lines are indented
+///
+pub fn detail_output(label: &str, value: &str) {
+    Console::new().detail(label, value);
+}
+
+///
+/// Print a diff, deletions from the expected value in red and insertions
+/// from the actual value in green
+///
+/// - `changes` The diff to print
+///
+pub fn diff_output(changes: &[Change]) {
+    Console::new().diff(changes);
 }
 
 ///
@@ -193,61 +376,15 @@ fn to_right(x: u16, description: &str) -> MoveRight {
 /// - `success` the failure eq zero
 /// - `s` The sussess message
 /// - `f` The failure message
+/// - `stats` The suite result
 ///
-pub fn results_output(success: bool, s: &str, f: &str, stats: &mut Unit) -> ExitCode {
-    if let Ok((x, _)) = size() {
-        let mut out: Stdout = stdout();
-        let status: String = if success {
-            SUCCESS.green().to_string()
-        } else {
-            FAILURE.red().to_string()
-        };
-        let symbol: String = if success {
-            "*".green().to_string()
-        } else {
-            "!".red().to_string()
-        };
-        let description: &str = if success { s } else { f };
-        assert!(
-            execute!(
-                out,
-                MoveLeft(0),
-                SetForegroundColor(Color::White),
-                Print(format!(
-                    "{} {}{}{}{}{}\n\n",
-                    symbol.bold(),
-                    description.to_lowercase().white().bold(),
-                    to_right(x, description),
-                    "[ ".white().bold(),
-                    status.bold(),
-                    " ]".white().bold(),
-                )),
-                ResetColor,
-            )
-            .is_ok()
-        );
-        return if success {
-            success_output(format!("asserts  {}", stats.get_assertions().get()).as_str());
-            success_output(format!("failure  {}", stats.get_failures().get()).as_str());
-            skip_output(format!("skipped  {}", stats.get_skipped().get()).as_str());
-
-            title_output(
-                format!("execution time {}s", stats.take().elapsed().as_secs()).as_str(),
-                SUCCESS,
-            );
-            ExitCode::SUCCESS
-        } else {
-            failure_ouptut(format!("asserts  {}", stats.get_assertions().get()).as_str());
-            failure_ouptut(format!("failure  {}", stats.get_failures().get()).as_str());
-            skip_output(format!("skipped  {}", stats.get_skipped().get()).as_str());
-            title_output(
-                format!("execution time {} ms", stats.take().elapsed().as_millis()).as_str(),
-                FAILURE,
-            );
-            ExitCode::FAILURE
-        };
+pub fn results_output(success: bool, s: &str, f: &str, stats: &Report) -> ExitCode {
+    Console::new().results(success, s, f, stats);
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
     }
-    ExitCode::FAILURE
 }
 
 ///
@@ -272,33 +409,8 @@ pub fn check(description: &str, test: bool, t: u64) -> bool {
 /// - `description` The test description
 ///
 pub fn failure_ouptut(description: &str) -> bool {
-    if let Ok((x, _)) = size() {
-        let mut out: Stdout = stdout();
-        let status: &str = FAILURE;
-        let symbol: char = '!';
-        assert!(
-            execute!(
-                out,
-                MoveLeft(0),
-                SetForegroundColor(Color::White),
-                Print(format!(
-                    "{} {}{}{}{}{}\n",
-                    symbol.red(),
-                    description.to_lowercase().white(),
-                    to_right(x, description),
-                    "[ ".white(),
-                    status.red(),
-                    " ]".white(),
-                )),
-                ResetColor,
-            )
-            .is_ok()
-        );
-        false
-    } else {
-        println!("! {description}");
-        false
-    }
+    Console::new().failure(description);
+    false
 }
 
 /// Represent a testing object
@@ -328,7 +440,12 @@ pub trait Testing {
     /// - `data` The data to check
     /// - `expected` The expected value
     ///
-    fn eq<T: PartialEq>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self;
+    fn eq<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self;
 
     ///
     /// Check if data are not equals to the expected value
@@ -337,7 +454,12 @@ pub trait Testing {
     /// - `data` The data to check
     /// - `expected` The expected value
     ///
-    fn ne<T: PartialEq>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self;
+    fn ne<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self;
 
     ///
     /// Map test cases in a group
@@ -345,7 +467,7 @@ pub trait Testing {
     /// - `description` the unit description
     /// - `it` The callback to excecute
     ///
-    fn group(&mut self, description: &str, it: fn(&mut Self) -> &mut Self) -> &mut Self;
+    fn group<F: FnOnce(&mut Self) -> &mut Self>(&mut self, description: &str, it: F) -> &mut Self;
 
     ///
     /// Check if a single value is equal to exected
@@ -354,7 +476,7 @@ pub trait Testing {
     /// - `value` A value to validate
     /// - `expected` The expected value
     ///
-    fn is<T: PartialEq>(&mut self, description: &str, value: T, expected: T) -> &mut Self;
+    fn is<T: PartialEq + Debug>(&mut self, description: &str, value: T, expected: T) -> &mut Self;
 
     ///
     /// Check if a single value is true
@@ -363,7 +485,7 @@ pub trait Testing {
     /// - `value` A value to validate
     /// - `expected` The expected value
     ///
-    fn not<T: PartialEq>(&mut self, description: &str, value: T, expected: T) -> &mut Self;
+    fn not<T: PartialEq + Debug>(&mut self, description: &str, value: T, expected: T) -> &mut Self;
 
     ///
     /// Check if length of the data is not equal to expected
@@ -372,11 +494,18 @@ pub trait Testing {
     /// - `data` The data to check
     /// - `expected` Expected number of elements
     ///
-    fn len<T: PartialEq>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self;
+    fn len<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self;
 
     ///
     /// check if data is full
     ///
+    /// A zero max fails, nothing can be full.
+    ///
     /// - `description` the test description
     /// - `min` the min value
     /// - `max` the max value
@@ -391,7 +520,12 @@ pub trait Testing {
     /// - `data` The data to check
     /// - `expected` Expected number of elements
     ///
-    fn gt<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self;
+    fn gt<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self;
 
     ///
     /// Check if length of the data is lower than to expected
@@ -400,7 +534,12 @@ pub trait Testing {
     /// - `data` The data to check
     /// - `expected` Expected number of elements
     ///
-    fn lt<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self;
+    fn lt<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self;
 
     ///
     /// Check if length of the data is greater equal than to expected
@@ -409,7 +548,12 @@ pub trait Testing {
     /// - `data` The data to check
     /// - `expected` Expected number of elements
     ///
-    fn ge<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self;
+    fn ge<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self;
 
     ///
     /// check if a string if empty
@@ -455,6 +599,25 @@ pub trait Testing {
     ///
     fn timed<F: FnOnce() -> bool>(&mut self, description: &str, f: F) -> &mut Self;
 
+    ///
+    /// Benchmark a function and display its timings
+    ///
+    /// The function is warmed up, then sampled as long as the measurement
+    /// lasts, fast functions being batched. Outliers are rejected and the
+    /// message reports min, mean, median, p95 and standard deviation in
+    /// nanoseconds per iteration.
+    ///
+    /// - `description` The benchmark description
+    /// - `budget` The time the median must stay under, if any
+    /// - `f` The function to benchmark
+    ///
+    fn bench<T, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        budget: Option<Duration>,
+        f: F,
+    ) -> &mut Self;
+
     fn take(&mut self) -> Instant;
 
     ///
@@ -463,10 +626,64 @@ pub trait Testing {
     /// - `description` The group title
     /// - `it` The callback that runs the group
     ///
-    fn subgroup(&mut self, description: &str, it: fn(&mut Self) -> &mut Self) -> &mut Self;
+    fn subgroup<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        it: F,
+    ) -> &mut Self;
+
+    ///
+    /// Map test cases in a tagged group
+    ///
+    /// - `description` the group description
+    /// - `tags` the group tags, like `slow` or `db`
+    /// - `it` The callback to excecute
+    ///
+    fn tagged_group<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self;
+
+    ///
+    /// Define a tagged sub-group of tests
+    ///
+    /// - `description` The group title
+    /// - `tags` The group tags, added to the inherited ones
+    /// - `it` The callback that runs the group
+    ///
+    fn tagged_subgroup<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self;
+
     fn get_assertions(&mut self) -> Cell<usize>;
     fn get_failures(&mut self) -> Cell<usize>;
     fn get_skipped(&mut self) -> Cell<usize>;
+    fn get_filtered(&mut self) -> Cell<usize>;
+
+    /// Get every assertion recorded so far, in order
+    fn get_records(&mut self) -> &[Assertion];
+
+    /// Get the result tree of the groups run so far
+    fn get_tree(&mut self) -> &Tree;
+
+    /// Get the seed of the generated data, printed in the summary
+    fn get_seed(&mut self) -> u64;
+
+    ///
+    /// Get the seeded generator of the last selected test
+    ///
+    /// Each test draws its inputs from a generator derived from the seed and
+    /// its full name.
+    ///
+    fn get_rng(&mut self) -> &mut StdRng;
+
+    /// Get the significance level of the statistical tests
+    fn get_significance(&mut self) -> f64;
 
     ///
     /// - `description` The test description
@@ -480,7 +697,12 @@ pub trait Testing {
     /// - `data` The data to check
     /// - `expected` Expected number of elements
     ///
-    fn le<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self;
+    fn le<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self;
 
     ///
     /// set sleep time
@@ -489,9 +711,113 @@ pub trait Testing {
     ///
     fn set_sleep_time(&mut self, time: u64) -> &mut Self;
 
+    ///
+    /// Run only the groups and tests matching a pattern
+    ///
+    /// The filter is read from `SOUL_FILTER` by default. A glob (`*`, `?`)
+    /// must match the whole name, any other pattern is a substring. Nested
+    /// groups are selected by their full path, like `parser > numbers`.
+    /// Only the groups whose path can still reach a match run, the others
+    /// don't execute and are counted as one filtered test.
+    ///
+    /// - `pattern` A substring or a glob
+    ///
+    fn set_filter(&mut self, pattern: &str) -> &mut Self;
+
+    ///
+    /// Select the groups to run by tags
+    ///
+    /// The selection is read from `SOUL_TAGS` by default. `!slow,db` runs
+    /// the groups tagged `db` unless they are also tagged `slow`. Groups that
+    /// are not selected don't run and are counted as filtered.
+    ///
+    /// - `expression` Comma separated tags, excluded ones prefixed by `!`
+    ///
+    fn set_tags(&mut self, expression: &str) -> &mut Self;
+
+    ///
+    /// Configure the suite from command line arguments
+    ///
+    /// The arguments of the libtest harness are understood, so a suite can
+    /// be a `harness = false` target run by `cargo test` or cargo-nextest:
+    ///
+    /// - a positional argument or `--filter <pattern>` sets the filter, the
+    ///   last one wins
+    /// - `--exact` makes the filter and the skips match whole names
+    /// - `--skip <pattern>` filters out the matching groups and tests
+    /// - `--list` prints the top level groups and the tests outside of any
+    ///   group as `name: test` lines instead of running them, `--ignored`
+    ///   lists and runs nothing
+    /// - `--format pretty|terse|tap` or `-q` chooses the output, the other
+    ///   formats like `json` fall back to terse
+    /// - `--tags <expression>` sets the tag selection
+    ///
+    /// Options accept the `--option=value` form. `--nocapture`,
+    /// `--test-threads` and the other arguments are ignored.
+    ///
+    /// The libtest JSON events are not emitted, the soul JSON Lines stream
+    /// is chosen with `SOUL_FORMAT=json`.
+    ///
+    /// - `args` The arguments, without the program name
+    ///
+    fn set_args(&mut self, args: Vec<String>) -> &mut Self;
+
+    ///
+    /// Stop the iterative assertions on their first failure
+    ///
+    /// In fail fast mode `always`, `confirm_contains_in`,
+    /// `confirm_not_contains_in` and `property` panic on the first failure
+    /// instead of recording it and continuing the suite.
+    ///
+    /// `fail_fast` enable or disable the mode
+    ///
+    fn set_fail_fast(&mut self, fail_fast: bool) -> &mut Self;
+
+    ///
+    /// Set the significance level of the statistical tests
+    ///
+    /// A statistical test fails when its p-value is below the level, `0.01`
+    /// by default.
+    ///
+    /// - `significance` The significance level, between 0 and 1
+    ///
+    /// # Panics
+    ///
+    /// When the level is not strictly between 0 and 1, `NaN` included
+    ///
+    fn set_significance(&mut self, significance: f64) -> &mut Self;
+
+    ///
+    /// Reseed the generated data
+    ///
+    /// The seed is read from `SOUL_SEED` by default, a random one is drawn
+    /// otherwise. Running again with the seed printed in the summary
+    /// generates exactly the same inputs.
+    ///
+    /// - `seed` The seed of the generator
+    ///
+    fn set_seed(&mut self, seed: u64) -> &mut Self;
+
+    ///
+    /// Add a reporter receiving the suite events
+    ///
+    /// - `reporter` The reporter to add
+    ///
+    fn add_reporter<R: Reporter + 'static>(&mut self, reporter: R) -> &mut Self;
+
+    ///
+    /// Replace all reporters, the console one included
+    ///
+    /// - `reporters` The new reporters
+    ///
+    fn set_reporters(&mut self, reporters: Vec<Box<dyn Reporter>>) -> &mut Self;
+
     /// Display the results
     fn run(&mut self) -> ExitCode;
 
+    /// Finish the suite, display the results and return them
+    fn finish(&mut self) -> Report;
+
     ///
     /// verify if the callback return alawys the same value in x iterations
     ///
@@ -501,12 +827,12 @@ pub trait Testing {
     /// - `expected` expected result
     /// - `c`the callback to execute
     ///
-    fn always<T: PartialEq>(
+    fn always<T: PartialEq, F: FnMut() -> T>(
         &mut self,
         description: &str,
         iteration: usize,
         expected: T,
-        c: fn() -> T,
+        c: F,
     ) -> &mut Self;
 
     ///
@@ -517,12 +843,12 @@ pub trait Testing {
     /// - `expected` expected result
     /// - `c` the callback to execute
     ///
-    fn confirm_contains_in<T: PartialEq>(
+    fn confirm_contains_in<T: PartialEq, F: FnMut() -> T>(
         &mut self,
         description: &str,
         iteration: usize,
         expected: Vec<T>,
-        c: fn() -> T,
+        c: F,
     ) -> &mut Self;
 
     ///
@@ -533,11 +859,362 @@ pub trait Testing {
     /// - `data` expected result
     /// - `c` the callback to execute
     ///
-    fn confirm_not_contains_in<T: PartialEq>(
+    fn confirm_not_contains_in<T: PartialEq, F: FnMut() -> T>(
         &mut self,
         description: &str,
         iteration: usize,
         expected: Vec<T>,
-        c: fn() -> T,
+        c: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check that a function gives the same output twice for generated inputs
+    ///
+    /// Every iteration is recorded, a failure prints the input and both
+    /// outputs.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs
+    /// - `func` the function to check
+    ///
+    fn deterministic<T, R, F>(&mut self, description: &str, iteration: usize, func: F) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R;
+
+    ///
+    /// Check that a mutation of generated inputs changes the output
+    ///
+    /// Every iteration is recorded, a failure prints the input, the mutated
+    /// input and their common output.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs
+    /// - `func` the function to check
+    /// - `mutate` the mutation applied to the input
+    ///
+    fn sensitive<T, R, F, M>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        func: F,
+        mutate: M,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        M: FnMut(&mut T);
+
+    ///
+    /// Check that an equivalent transformation of generated inputs keeps
+    /// the output
+    ///
+    /// Every iteration is recorded, a failure prints both inputs and both
+    /// outputs.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs
+    /// - `func` the function to check
+    /// - `equiv` the equivalent transformation of the input
+    ///
+    fn invariant<T, R, F, E>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        func: F,
+        equiv: E,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        E: FnMut(T) -> T;
+
+    ///
+    /// Check metamorphic relations of a function over generated inputs
+    ///
+    /// Every relation is recorded per iteration as `description: name`, a
+    /// failure prints both inputs and both outputs.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs per relation
+    /// - `func` the function to check
+    /// - `relations` the relations between source and follow-up runs
+    ///
+    fn metamorphic<T, R, F>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        func: F,
+        relations: Vec<Relation<'_, T, R>>,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: Debug,
+        F: FnMut(T) -> R;
+
+    ///
+    /// Check that a function gives the same outputs as a reference
+    /// implementation over generated inputs
+    ///
+    /// Every iteration is recorded. A divergence is shrunk to a minimal
+    /// input when `Shrink` has candidates for it, and prints the input with
+    /// both outputs.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs
+    /// - `reference` the reference implementation
+    /// - `candidate` the implementation to check
+    ///
+    fn differential<T, R, F, G>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        reference: F,
+        candidate: G,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Shrink + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        G: FnMut(T) -> R;
+
+    ///
+    /// Check the `Eq` laws of a type over generated inputs
+    ///
+    /// Reflexivity, symmetry, transitivity and the consistency of `ne` are
+    /// each recorded as one assertion, `description: law`.
+    ///
+    /// - `description` the test description, usually the type name
+    /// - `iteration` the number of generated inputs
+    ///
+    fn eq_laws<T: Dummy<Faker> + Eq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self;
+
+    ///
+    /// Check the `Ord` laws of a type over generated inputs
+    ///
+    /// Antisymmetry, transitivity and the consistency with `PartialEq` and
+    /// `PartialOrd` are each recorded as one assertion.
+    ///
+    /// - `description` the test description, usually the type name
+    /// - `iteration` the number of generated inputs
+    ///
+    fn ord_laws<T: Dummy<Faker> + Ord + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self;
+
+    ///
+    /// Check that equal values of a type have equal hashes
+    ///
+    /// - `description` the test description, usually the type name
+    /// - `iteration` the number of generated inputs
+    ///
+    fn hash_laws<T: Dummy<Faker> + Hash + Eq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self;
+
+    ///
+    /// Check that the clones of a type are equal to the original
+    ///
+    /// - `description` the test description, usually the type name
+    /// - `iteration` the number of generated inputs
+    ///
+    fn clone_laws<T: Dummy<Faker> + PartialEq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self;
+
+    ///
+    /// Check every law of a type over generated inputs
+    ///
+    /// Runs the `Eq`, `Ord`, `Hash` and `Clone` laws, each law is recorded
+    /// as its own assertion, `description eq: reflexive` and so on.
+    ///
+    /// - `description` the test description, usually the type name
+    /// - `iteration` the number of generated inputs
+    ///
+    fn laws<T: Dummy<Faker> + Ord + Hash + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self;
+
+    ///
+    /// Check that flipping any input bit flips half of the output bits
+    ///
+    /// Every bit of random inputs of `length` bytes is flipped in turn. The
+    /// test passes when the flip rate of every input bit is within the
+    /// tolerance of 50%, the message reports the measured rates. An empty
+    /// input has no bit to flip and fails.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of random inputs
+    /// - `length` the number of bytes of each input, like `16`
+    /// - `tolerance` the accepted distance to 50%, like `0.05`
+    /// - `hash` the hash function
+    ///
+    fn avalanche<F: FnMut(&[u8]) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        length: usize,
+        tolerance: f64,
+        hash: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check the collision rate of a hash over generated inputs
+    ///
+    /// The rate is the number of collisions divided by the number of
+    /// distinct inputs, the message reports it with the threshold. No input
+    /// fails the test.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs
+    /// - `max_rate` the highest accepted rate, like `0.001`
+    /// - `hash` the hash function
+    ///
+    fn collisions<T: Dummy<Faker> + Hash + Eq, F: FnMut(&T) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        max_rate: f64,
+        hash: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check that a hash spreads generated inputs uniformly over buckets
+    ///
+    /// The bucket counts go through a chi-square test at the significance
+    /// level, the message reports the statistic and its p-value. No input or
+    /// fewer than 2 buckets fail the test.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs
+    /// - `buckets` the number of buckets, the hash modulo this number
+    /// - `hash` the hash function
+    ///
+    fn buckets<T: Dummy<Faker>, F: FnMut(&T) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        buckets: usize,
+        hash: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check that samples are uniformly spread over categories
+    ///
+    /// The counts go through a chi-square test at the significance level. A
+    /// sample outside of the categories or no sample at all fails the test.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of samples
+    /// - `categories` the expected values
+    /// - `sample` draws a sample from the seeded generator
+    ///
+    fn uniform<T: PartialEq + Debug, F: FnMut(&mut StdRng) -> T>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        categories: Vec<T>,
+        sample: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check the mean of samples
+    ///
+    /// A two-sided z test at the significance level, the message reports
+    /// the mean with its confidence bounds. No sample fails the test.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of samples
+    /// - `expected` the expected mean
+    /// - `sample` draws a sample from the seeded generator
+    ///
+    fn mean<F: FnMut(&mut StdRng) -> f64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: f64,
+        sample: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check the variance of samples
+    ///
+    /// A two-sided chi-square test at the significance level, which assumes
+    /// normally distributed samples. The message reports the variance with
+    /// its confidence bounds, fewer than 2 samples fail the test.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of samples
+    /// - `expected` the expected variance, greater than 0
+    /// - `sample` draws a sample from the seeded generator
+    ///
+    /// # Panics
+    ///
+    /// When the expected variance is not greater than 0, `NaN` included
+    ///
+    fn variance<F: FnMut(&mut StdRng) -> f64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: f64,
+        sample: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check the frequency of an outcome
+    ///
+    /// A two-sided z test at the significance level, of the distance
+    /// between the observed and expected frequencies beyond the tolerance.
+    /// No sample fails the test.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of samples
+    /// - `expected` the expected frequency, between 0 and 1
+    /// - `tolerance` the accepted distance to the expected frequency
+    /// - `sample` returns true when the outcome happens
+    ///
+    /// # Panics
+    ///
+    /// When the expected frequency is not between 0 and 1, `NaN` included
+    ///
+    fn frequency<F: FnMut(&mut StdRng) -> bool>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: f64,
+        tolerance: f64,
+        sample: F,
+    ) -> &mut Self;
+
+    ///
+    /// Check a property over generated inputs
+    ///
+    /// Inputs are generated with `Faker`. The first failing input is shrunk
+    /// to a minimal counterexample, reported in the failure message.
+    ///
+    /// - `description` the test description
+    /// - `iteration` the number of generated inputs
+    /// - `property` returns true when the property holds for the input
+    ///
+    fn property<T: Dummy<Faker> + Shrink + Clone + Debug, F: FnMut(T) -> bool>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        property: F,
     ) -> &mut Self;
 }
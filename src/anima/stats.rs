@@ -0,0 +1,246 @@
+use std::f64::consts::PI;
+
+/// The significance level of the statistical tests by default
+pub const DEFAULT_SIGNIFICANCE: f64 = 0.01;
+
+/// The maximum number of terms of the series and continued fractions
+const MAX_TERMS: usize = 500;
+
+/// The relative precision of the series and continued fractions
+const EPSILON: f64 = 1e-14;
+
+/// The smallest value kept by the continued fractions to avoid dividing by zero
+const TINY: f64 = 1e-300;
+
+/// The Lanczos coefficients for g = 7
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+///
+/// Compute the chi-square statistic of observed counts
+///
+/// - `observed` The observed counts
+/// - `expected` The expected counts, one per observed count
+///
+#[must_use]
+pub fn chi_square(observed: &[usize], expected: &[f64]) -> f64 {
+    observed
+        .iter()
+        .zip(expected)
+        .filter(|(_, e)| **e > 0.0)
+        .map(|(o, e)| (*o as f64 - e).powi(2) / e)
+        .sum()
+}
+
+///
+/// Get the probability of a chi-square statistic at least as large
+///
+/// - `statistic` The chi-square statistic
+/// - `freedom` The degrees of freedom
+///
+#[must_use]
+pub fn chi_square_p_value(statistic: f64, freedom: usize) -> f64 {
+    if freedom.eq(&0) {
+        return 1.0;
+    }
+    gamma_q(freedom as f64 / 2.0, statistic / 2.0)
+}
+
+///
+/// Get the chi-square statistic whose probability to be exceeded is given
+///
+/// - `probability` The upper tail probability, between 0 and 1
+/// - `freedom` The degrees of freedom
+///
+#[must_use]
+pub fn chi_square_quantile(probability: f64, freedom: usize) -> f64 {
+    if freedom.eq(&0) {
+        return 0.0;
+    }
+    let (mut low, mut high): (f64, f64) = (0.0, freedom as f64 + 10.0);
+    while chi_square_p_value(high, freedom) > probability {
+        high *= 2.0;
+    }
+    for _ in 0..MAX_TERMS {
+        let middle: f64 = (low + high) / 2.0;
+        if chi_square_p_value(middle, freedom) > probability {
+            low = middle;
+        } else {
+            high = middle;
+        }
+        if high - low < EPSILON * high.max(1.0) {
+            break;
+        }
+    }
+    (low + high) / 2.0
+}
+
+///
+/// Get the two-sided probability of a standard normal value at least as far
+/// from zero
+///
+/// - `z` The standard score
+///
+#[must_use]
+pub fn normal_p_value(z: f64) -> f64 {
+    gamma_q(0.5, z * z / 2.0)
+}
+
+///
+/// Get the standard score whose two-sided probability is the significance
+///
+/// - `significance` The significance level, between 0 and 1
+///
+#[must_use]
+pub fn normal_critical(significance: f64) -> f64 {
+    let (mut low, mut high): (f64, f64) = (0.0, 40.0);
+    for _ in 0..MAX_TERMS {
+        let middle: f64 = (low + high) / 2.0;
+        if normal_p_value(middle) > significance {
+            low = middle;
+        } else {
+            high = middle;
+        }
+        if high - low < EPSILON {
+            break;
+        }
+    }
+    (low + high) / 2.0
+}
+
+///
+/// Compute the mean of samples
+///
+/// - `samples` The samples
+///
+#[must_use]
+pub fn mean(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+///
+/// Compute the unbiased variance of samples
+///
+/// - `samples` The samples
+///
+#[must_use]
+pub fn variance(samples: &[f64]) -> f64 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    let mean: f64 = mean(samples);
+    samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (samples.len() - 1) as f64
+}
+
+///
+/// Compute the natural logarithm of the gamma function
+///
+/// - `x` A positive value
+///
+fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x: f64 = x - 1.0;
+    let t: f64 = x + 7.5;
+    let sum: f64 = LANCZOS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(LANCZOS[0], |sum, (i, c)| sum + c / (x + i as f64));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+///
+/// Compute the upper regularized gamma function `Q(a, x)`
+///
+/// - `a` The shape
+/// - `x` The lower bound of the integral
+///
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefix: f64 = (a * x.ln() - x - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        let mut term: f64 = 1.0 / a;
+        let mut sum: f64 = term;
+        let mut n: f64 = a;
+        for _ in 0..MAX_TERMS {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (1.0 - prefix * sum).clamp(0.0, 1.0)
+    } else {
+        let mut b: f64 = x + 1.0 - a;
+        let mut c: f64 = 1.0 / TINY;
+        let mut d: f64 = 1.0 / b;
+        let mut h: f64 = d;
+        for i in 1..MAX_TERMS {
+            let an: f64 = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = b + an / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            let delta: f64 = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        (prefix * h).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::anima::stats::{
+        chi_square, chi_square_p_value, chi_square_quantile, mean, normal_critical, normal_p_value,
+        variance,
+    };
+
+    #[test]
+    pub fn chi_square_table() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-3;
+        assert!(close(chi_square_p_value(3.841, 1), 0.05));
+        assert!(close(chi_square_p_value(18.307, 10), 0.05));
+        assert!(close(chi_square_p_value(6.635, 1), 0.01));
+        assert!(close(chi_square_p_value(0.0, 4), 1.0));
+        assert!(close(chi_square(&[10, 30], &[20.0, 20.0]), 10.0));
+        assert!(close(chi_square_quantile(0.05, 1), 3.841));
+        assert!(close(chi_square_quantile(0.975, 10), 3.247));
+        assert!(close(chi_square_quantile(0.025, 10), 20.483));
+    }
+
+    #[test]
+    pub fn normal() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-3;
+        assert!(close(normal_p_value(1.96), 0.05));
+        assert!(close(normal_p_value(0.0), 1.0));
+        assert!(close(normal_critical(0.01), 2.576));
+        assert!(close(mean(&[1.0, 2.0, 6.0]), 3.0));
+        assert!(close(variance(&[1.0, 2.0, 6.0]), 7.0));
+    }
+}
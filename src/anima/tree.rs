@@ -0,0 +1,205 @@
+use super::reporter::Status;
+use std::time::Duration;
+
+/// Distinguish a group from a subgroup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupKind {
+    Group,
+    Subgroup,
+}
+
+/// The number of assertions by outcome, filtered tests and groups apart
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub filtered: usize,
+}
+
+impl Counts {
+    ///
+    /// Count an assertion
+    ///
+    /// - `status` The assertion outcome
+    ///
+    pub fn record(&mut self, status: Status) {
+        match status {
+            Status::Pass => self.passed += 1,
+            Status::Fail => self.failed += 1,
+            Status::Skip => self.skipped += 1,
+        }
+    }
+
+    /// Get the number of assertions run, filtered ones excluded
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.passed + self.failed + self.skipped
+    }
+
+    ///
+    /// Add the counts of another node
+    ///
+    /// - `other` The counts to add
+    ///
+    #[must_use]
+    pub const fn add(self, other: Self) -> Self {
+        Self {
+            passed: self.passed + other.passed,
+            failed: self.failed + other.failed,
+            skipped: self.skipped + other.skipped,
+            filtered: self.filtered + other.filtered,
+        }
+    }
+}
+
+/// A group of the result tree
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub id: usize,
+    pub description: String,
+    pub path: String,
+    pub kind: GroupKind,
+    pub depth: usize,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+    pub tags: Vec<String>,
+    pub counts: Counts,
+    pub duration: Duration,
+    pub filtered: bool,
+}
+
+/// The separator between the group names of a path
+pub const PATH_SEPARATOR: &str = " > ";
+
+///
+/// The groups of a suite, in the order they started
+///
+/// Each group knows its parent and its children, assertions made outside
+/// of any group are not part of the tree.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Tree {
+    groups: Vec<Group>,
+}
+
+impl Tree {
+    ///
+    /// Open a new group and return its id
+    ///
+    /// - `description` The group title
+    /// - `kind` Group or subgroup
+    /// - `parent` The enclosing group
+    /// - `tags` The group tags, the parent ones are inherited
+    ///
+    pub fn open(
+        &mut self,
+        description: &str,
+        kind: GroupKind,
+        parent: Option<usize>,
+        tags: &[&str],
+    ) -> usize {
+        let id: usize = self.groups.len();
+        let (path, depth, mut inherited): (String, usize, Vec<String>) =
+            match parent.and_then(|p| self.groups.get(p)) {
+                Some(p) => (
+                    format!("{}{PATH_SEPARATOR}{description}", p.path),
+                    p.depth + 1,
+                    p.tags.clone(),
+                ),
+                None => (description.to_string(), 0, Vec::new()),
+            };
+        for tag in tags {
+            if !inherited.iter().any(|t| t.eq(tag)) {
+                inherited.push((*tag).to_string());
+            }
+        }
+        self.groups.push(Group {
+            id,
+            description: description.to_string(),
+            path,
+            kind,
+            depth,
+            parent,
+            tags: inherited,
+            children: Vec::new(),
+            counts: Counts::default(),
+            duration: Duration::ZERO,
+            filtered: false,
+        });
+        if let Some(p) = parent.and_then(|p| self.groups.get_mut(p)) {
+            p.children.push(id);
+        }
+        id
+    }
+
+    ///
+    /// Drop the descendants of the last opened subtree and reset its counts
+    ///
+    /// The descendants of a group are the last groups opened, as long as no
+    /// group was opened after it was left.
+    ///
+    /// - `id` The root of the subtree
+    ///
+    pub fn prune(&mut self, id: usize) {
+        self.groups.truncate(id + 1);
+        if let Some(group) = self.groups.get_mut(id) {
+            group.children.clear();
+            group.counts = Counts::default();
+        }
+    }
+
+    ///
+    /// Get a group by id
+    ///
+    /// - `id` The group id
+    ///
+    #[must_use]
+    pub fn get(&self, id: usize) -> Option<&Group> {
+        self.groups.get(id)
+    }
+
+    ///
+    /// Get a mutable group by id
+    ///
+    /// - `id` The group id
+    ///
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut Group> {
+        self.groups.get_mut(id)
+    }
+
+    /// Get every group in start order
+    #[must_use]
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+
+    /// Get the top level groups
+    pub fn roots(&self) -> impl Iterator<Item = &Group> {
+        self.groups.iter().filter(|g| g.parent.is_none())
+    }
+
+    ///
+    /// Find a group by its path, like `parser > numbers > overflow`
+    ///
+    /// - `path` The group path
+    ///
+    #[must_use]
+    pub fn find(&self, path: &str) -> Option<&Group> {
+        self.groups.iter().find(|g| g.path.eq(path))
+    }
+
+    ///
+    /// Get the counts of a group and all its descendants
+    ///
+    /// - `id` The group id
+    ///
+    #[must_use]
+    pub fn totals(&self, id: usize) -> Counts {
+        self.groups.get(id).map_or_else(Counts::default, |group| {
+            group.children.iter().fold(group.counts, |counts, child| {
+                counts.add(self.totals(*child))
+            })
+        })
+    }
+}
@@ -1,251 +1,1676 @@
 #![allow(clippy::multiple_crate_versions)]
 
-use super::soul::{
-    DEFAULT_SLEEP_TIME, SUCCESS, failure_ouptut, results_output, skip_output, success_output,
-    title_output,
-};
-use crate::anima::soul::{Testing, check};
+use super::bench::{self, Measure};
+use super::diff;
+use super::metamorphic::Relation;
+use super::report::Report;
+use super::reporter::{self, Assertion, Reporter, Status};
+use super::select::{Filter, Tags};
+use super::shrink::{self, Shrink};
+use super::soul::{self, DEFAULT_SLEEP_TIME};
+use super::stats;
+use super::tree::{Counts, GroupKind, PATH_SEPARATOR, Tree};
+use crate::anima::soul::Testing;
+use fake::{Dummy, Fake, Faker};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::mem;
+use std::thread::sleep;
+use std::time::Duration;
 use std::{cell::Cell, ops::Add, process::ExitCode, time::Instant};
+
+/// The offset basis of the FNV-1a hash deriving the generator of a test
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of the FNV-1a hash deriving the generator of a test
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A group being run, from its enter event to its exit event
+pub(crate) struct Scope {
+    id: usize,
+    parent: Option<usize>,
+    inherited: bool,
+    started_at: Instant,
+}
+
+/// The top level groups and tests collected by `--list` instead of running them
+struct Listing {
+    terse: bool,
+    names: Vec<String>,
+}
+
 pub struct Unit {
     sleep_time: u64,
     started_at: Instant,
     asserts: Cell<usize>,
     failures: Cell<usize>,
     skipped: Cell<usize>,
+    filtered: Cell<usize>,
+    filter: Option<Filter>,
+    tags: Option<Tags>,
+    skips: Vec<Filter>,
+    listing: Option<Listing>,
+    selected: bool,
+    fail_fast: bool,
+    records: Vec<Assertion>,
+    reporters: Vec<Box<dyn Reporter>>,
+    started: bool,
+    tree: Tree,
+    current: Option<usize>,
+    pending: Vec<usize>,
+    mark: Instant,
+    seed: u64,
+    rng: StdRng,
+    significance: f64,
+}
+
+impl Unit {
+    ///
+    /// Send an event to every reporter, starting the suite on the first one
+    ///
+    /// - `event` The event to send
+    ///
+    fn emit<F: FnMut(&mut dyn Reporter)>(&mut self, mut event: F) {
+        if !self.started {
+            self.started = true;
+            for reporter in &mut self.reporters {
+                reporter.suite_start();
+            }
+        }
+        for reporter in &mut self.reporters {
+            event(reporter.as_mut());
+        }
+    }
+
+    ///
+    /// Record an assertion, update the counters, report it and return true
+    /// if it succeed
+    ///
+    /// Every assertion of the suite goes through this method.
+    ///
+    /// - `assertion` The assertion to record
+    ///
+    pub(crate) fn report(&mut self, mut assertion: Assertion) -> bool {
+        if self.lists(&assertion.description) {
+            return true;
+        }
+        if !self.selects(&assertion.description) {
+            self.filter_out(None);
+            return true;
+        }
+        self.announce();
+        assertion.group = self.current;
+        if let Some(group) = self.current.and_then(|id| self.tree.get_mut(id)) {
+            group.counts.record(assertion.status);
+        }
+        let counter: &Cell<usize> = match assertion.status {
+            Status::Pass => &self.asserts,
+            Status::Fail => &self.failures,
+            Status::Skip => &self.skipped,
+        };
+        counter.set(counter.get() + 1);
+        self.emit(|r| match assertion.status {
+            Status::Pass => r.pass(&assertion),
+            Status::Fail => r.fail(&assertion),
+            Status::Skip => r.skip(&assertion),
+        });
+        self.mark = Instant::now();
+        let success: bool = assertion.status.eq(&Status::Pass);
+        self.records.push(assertion);
+        success
+    }
+
+    ///
+    /// Get the name of a test or a group in the current group
+    ///
+    /// - `description` The test description or the group title
+    ///
+    fn name(&self, description: &str) -> String {
+        self.current.and_then(|id| self.tree.get(id)).map_or_else(
+            || description.to_string(),
+            |group| format!("{}{PATH_SEPARATOR}{description}", group.path),
+        )
+    }
+
+    ///
+    /// Derive the generator of a test from the seed and its full name
+    ///
+    /// Every test draws from its own stream, so filtering or adding other
+    /// tests doesn't change its inputs and a seed replays a single test.
+    ///
+    /// - `description` The test description
+    ///
+    fn generator(&self, description: &str) -> StdRng {
+        let key: u64 = self
+            .name(description)
+            .bytes()
+            .fold(FNV_OFFSET ^ self.seed, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            });
+        StdRng::seed_from_u64(key)
+    }
+
+    ///
+    /// Check if a test of the current group is selected by the filter
+    ///
+    /// Groups only run when their tags are selected, tests outside of any
+    /// group are selected like an untagged group. Nothing runs while
+    /// listing.
+    ///
+    /// - `description` The test description
+    ///
+    fn selects(&self, description: &str) -> bool {
+        let name: String = self.name(description);
+        let tagged: bool = self.current.is_some()
+            || self
+                .tags
+                .as_ref()
+                .is_none_or(|selection| selection.selects(&[]));
+        self.listing.is_none()
+            && tagged
+            && !self.skips(&name)
+            && (self.selected || self.filter.as_ref().is_none_or(|f| f.matches(&name)))
+    }
+
+    ///
+    /// Collect a test made outside of any group while listing
+    ///
+    /// Return true while listing, the test must not run.
+    ///
+    /// - `description` The test description
+    ///
+    fn lists(&mut self, description: &str) -> bool {
+        let Some(mut listing) = self.listing.take() else {
+            return false;
+        };
+        let selected: bool = self.selects(description);
+        if self.current.is_none() && selected && !listing.names.iter().any(|n| n.eq(description)) {
+            listing.names.push(description.to_string());
+        }
+        self.listing = Some(listing);
+        true
+    }
+
+    ///
+    /// Check if a test or a group is skipped by a `--skip` filter
+    ///
+    /// - `name` The test name or the group path
+    ///
+    fn skips(&self, name: &str) -> bool {
+        self.skips.iter().any(|skip| skip.matches(name))
+    }
+
+    ///
+    /// Count a filtered test or group in the current group
+    ///
+    /// - `group` The filtered group id, none for an assertion
+    ///
+    fn filter_out(&mut self, group: Option<usize>) {
+        self.filtered.set(self.filtered.get() + 1);
+        if let Some(filtered) = group.and_then(|id| self.tree.get_mut(id)) {
+            filtered.filtered = true;
+        }
+        if let Some(parent) = self.current.and_then(|id| self.tree.get_mut(id)) {
+            parent.counts.filtered += 1;
+        }
+    }
+
+    ///
+    /// Report a success or a failure
+    ///
+    /// - `description` The test description
+    /// - `test` The test result
+    ///
+    fn check(&mut self, description: &str, test: bool) -> bool {
+        sleep(Duration::from_millis(self.sleep_time));
+        let status: Status = if test { Status::Pass } else { Status::Fail };
+        let assertion: Assertion = Assertion::new(description, status, self.mark.elapsed());
+        self.report(assertion)
+    }
+
+    ///
+    /// Compare every element of data with the expected value and report
+    /// both values on failure
+    ///
+    /// - `description` The test description
+    /// - `data` The data to check
+    /// - `expected` The expected value
+    /// - `op` The operator displayed before the expected value
+    /// - `test` The comparison
+    ///
+    fn compare<T: Debug, F: Fn(&T, &T) -> bool>(
+        &mut self,
+        description: &str,
+        data: &[T],
+        expected: &T,
+        op: &str,
+        test: F,
+    ) -> &mut Self {
+        for (index, actual) in data.iter().enumerate() {
+            sleep(Duration::from_millis(self.sleep_time));
+            let mut assertion: Assertion = if test(actual, expected) {
+                Assertion::new(description, Status::Pass, self.mark.elapsed())
+            } else {
+                let assertion: Assertion =
+                    Assertion::new(description, Status::Fail, self.mark.elapsed())
+                        .with_values(format!("{op}{expected:?}"), format!("{actual:?}"));
+                if op.is_empty() {
+                    assertion.with_diff(diff::debug(expected, actual))
+                } else {
+                    assertion
+                }
+            };
+            if data.len() > 1 {
+                assertion.index = Some(index);
+            }
+            self.report(assertion);
+        }
+        self
+    }
+
+    ///
+    /// Run a test many times and record every iteration
+    ///
+    /// Failed iterations report their index and how many iterations passed.
+    /// In fail fast mode the iterations stop and the suite panics on the
+    /// first failure.
+    ///
+    /// - `description` The test description
+    /// - `iteration` The number of iterations
+    /// - `test` The test to run, returns true on success
+    ///
+    fn iterate<F: FnMut() -> bool>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut test: F,
+    ) -> &mut Self {
+        self.trials(description, iteration, || {
+            if test() { None } else { Some(String::new()) }
+        })
+    }
+
+    ///
+    /// Run a test many times and record every iteration with its failure
+    ///
+    /// - `description` The test description
+    /// - `iteration` The number of iterations
+    /// - `test` The test to run, returns the failure detail, if any
+    ///
+    fn trials<F: FnMut() -> Option<String>>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut test: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let mut results: Vec<(Option<String>, Duration)> = Vec::with_capacity(iteration);
+        for _ in 0..iteration {
+            let i: Instant = Instant::now();
+            let failure: Option<String> = test();
+            let failed: bool = failure.is_some();
+            results.push((failure, i.elapsed()));
+            if failed && self.fail_fast {
+                break;
+            }
+        }
+        let passed: usize = results.iter().filter(|(f, _)| f.is_none()).count();
+        let ran: usize = results.len();
+        for (index, (failure, duration)) in results.into_iter().enumerate() {
+            sleep(Duration::from_millis(self.sleep_time));
+            let assertion: Assertion = match failure {
+                None => Assertion::new(description, Status::Pass, duration),
+                Some(detail) => {
+                    let mut message: String = format!(
+                        "iteration {} of {iteration} failed, {passed}/{ran} passed",
+                        index + 1
+                    );
+                    if !detail.is_empty() {
+                        message.push_str(format!(", {detail}").as_str());
+                    }
+                    let mut assertion: Assertion =
+                        Assertion::new(description, Status::Fail, duration).with_message(&message);
+                    assertion.index = Some(index);
+                    assertion
+                }
+            };
+            self.report(assertion);
+        }
+        if self.fail_fast && passed.lt(&ran) {
+            panic!("{description}: iteration {ran} of {iteration} failed");
+        }
+        self
+    }
+
+    ///
+    /// Run a test on generated inputs and record every iteration
+    ///
+    /// The inputs are drawn from the seeded generator once the test is
+    /// selected.
+    ///
+    /// - `description` The test description
+    /// - `iteration` The number of inputs
+    /// - `test` The test to run on an input, returns the failure detail
+    ///
+    fn generated<T: Dummy<Faker>, F: FnMut(T) -> Option<String>>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut test: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let inputs: Vec<T> = (0..iteration)
+            .map(|_| Faker.fake_with_rng(&mut self.rng))
+            .collect();
+        let mut inputs = inputs.into_iter();
+        self.trials(description, iteration, || inputs.next().and_then(&mut test))
+    }
+
+    ///
+    /// Draw triples of inputs for the law checks
+    ///
+    /// Random values are rarely equal, so three iterations out of four
+    /// reuse clones: `(x, y, z)`, `(x, x, z)`, `(x, y, y)` then `(x, x, x)`.
+    /// The triples are drawn from the generator of the laws description.
+    ///
+    /// - `description` The laws description
+    /// - `iteration` The number of triples
+    ///
+    fn triples<T: Dummy<Faker> + Clone>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> Vec<(T, T, T)> {
+        self.rng = self.generator(description);
+        (0..iteration)
+            .map(|i| {
+                let x: T = Faker.fake_with_rng(&mut self.rng);
+                let y: T = if i % 2 == 1 {
+                    x.clone()
+                } else {
+                    Faker.fake_with_rng(&mut self.rng)
+                };
+                let z: T = if i % 4 >= 2 {
+                    y.clone()
+                } else {
+                    Faker.fake_with_rng(&mut self.rng)
+                };
+                (x, y, z)
+            })
+            .collect()
+    }
+
+    ///
+    /// Check a law on every triple and record it as one assertion
+    ///
+    /// A failure prints the first triple breaking the law.
+    ///
+    /// - `description` The law description
+    /// - `triples` The inputs
+    /// - `holds` Returns true when the law holds for a triple
+    ///
+    fn law<T: Debug, F: Fn(&T, &T, &T) -> bool>(
+        &mut self,
+        description: &str,
+        triples: &[(T, T, T)],
+        holds: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        let broken: Option<usize> = triples.iter().position(|(a, b, c)| !holds(a, b, c));
+        let Some(index) = broken else {
+            self.report(Assertion::new(description, Status::Pass, i.elapsed()));
+            return self;
+        };
+        let (a, b, c): &(T, T, T) = &triples[index];
+        let mut assertion: Assertion = Assertion::new(description, Status::Fail, i.elapsed())
+            .with_message(
+                format!(
+                    "broken by {a:?}, {b:?}, {c:?} after {} of {} inputs",
+                    index + 1,
+                    triples.len()
+                )
+                .as_str(),
+            );
+        assertion.index = Some(index);
+        self.report(assertion);
+        if self.fail_fast {
+            panic!("{description}: broken by {a:?}, {b:?}, {c:?}");
+        }
+        self
+    }
+
+    ///
+    /// Record a measured assertion with its measure as message
+    ///
+    /// - `description` The test description
+    /// - `passed` True when the measure is within its threshold
+    /// - `started_at` The start of the measure
+    /// - `measure` The measured value and its threshold
+    ///
+    fn measured(
+        &mut self,
+        description: &str,
+        passed: bool,
+        started_at: Instant,
+        measure: &str,
+    ) -> &mut Self {
+        let status: Status = if passed { Status::Pass } else { Status::Fail };
+        self.report(
+            Assertion::new(description, status, started_at.elapsed()).with_message(measure),
+        );
+        self
+    }
+
+    ///
+    /// Record a statistical test, passing when its p-value reaches the
+    /// significance level
+    ///
+    /// - `description` The test description
+    /// - `started_at` The start of the test
+    /// - `p_value` The p-value of the test
+    /// - `measure` The computed statistic
+    ///
+    fn significant(
+        &mut self,
+        description: &str,
+        started_at: Instant,
+        p_value: f64,
+        measure: &str,
+    ) -> &mut Self {
+        let significance: f64 = self.significance;
+        self.measured(
+            description,
+            p_value.ge(&significance),
+            started_at,
+            format!("{measure}, p-value {p_value:.4}, significance {significance}").as_str(),
+        )
+    }
+
+    ///
+    /// Record a statistical test drawing too few samples as failed
+    ///
+    /// Returns true when the test has enough samples to go on.
+    ///
+    /// - `description` The test description
+    /// - `started_at` The start of the test
+    /// - `iteration` The number of samples
+    /// - `minimum` The number of samples the statistic needs
+    ///
+    fn sampled(
+        &mut self,
+        description: &str,
+        started_at: Instant,
+        iteration: usize,
+        minimum: usize,
+    ) -> bool {
+        if iteration.ge(&minimum) {
+            return true;
+        }
+        self.measured(
+            description,
+            false,
+            started_at,
+            format!("{iteration} samples drawn, at least {minimum} needed").as_str(),
+        );
+        false
+    }
+
+    ///
+    /// Check if a test is selected, counting it as filtered otherwise
+    ///
+    /// - `description` The test description
+    ///
+    pub(crate) fn admits(&mut self, description: &str) -> bool {
+        if self.lists(description) {
+            return false;
+        }
+        if self.selects(description) {
+            self.rng = self.generator(description);
+            return true;
+        }
+        self.filter_out(None);
+        false
+    }
+
+    ///
+    /// Enter a group or a subgroup and send its enter event
+    ///
+    /// Return `None` when the group must not run, because it is filtered
+    /// or only listed.
+    ///
+    /// - `description` The group title
+    /// - `kind` Group or subgroup
+    /// - `tags` The group tags
+    ///
+    pub(crate) fn enter(
+        &mut self,
+        description: &str,
+        kind: GroupKind,
+        tags: &[&str],
+    ) -> Option<Scope> {
+        let parent: Option<usize> = self.current;
+        let path: String = self.name(description);
+        let selected: bool = self.selected || self.filter.as_ref().is_none_or(|f| f.matches(&path));
+        let id: usize = self.tree.open(description, kind, parent, tags);
+        let tagged: bool = self.tree.get(id).is_some_and(|group| {
+            self.tags
+                .as_ref()
+                .is_none_or(|selection| selection.selects(&group.tags))
+        });
+        if !tagged
+            || self.skips(&path)
+            || (!selected && !self.filter.as_ref().is_some_and(|f| f.enters(&path)))
+        {
+            self.filter_out(Some(id));
+            return None;
+        }
+        if let Some(listing) = self.listing.as_mut() {
+            if parent.is_none() && !listing.names.contains(&path) {
+                listing.names.push(path);
+            }
+            return None;
+        }
+        let scope: Scope = Scope {
+            id,
+            parent,
+            inherited: self.selected,
+            started_at: Instant::now(),
+        };
+        self.selected = selected;
+        self.pending.push(id);
+        if selected {
+            self.announce();
+        }
+        self.mark = Instant::now();
+        self.current = Some(id);
+        Some(scope)
+    }
+
+    ///
+    /// Send the enter events of the groups waiting for a selected test
+    ///
+    /// A group entered only to reach its descendants waits until one of
+    /// them is selected, so that a group without any is never reported.
+    ///
+    fn announce(&mut self) {
+        for id in mem::take(&mut self.pending) {
+            if let Some(group) = self.tree.get(id).cloned() {
+                self.emit(|r| r.group_enter(&group));
+            }
+        }
+    }
+
+    ///
+    /// Leave a group entered by `enter` and send its exit event
+    ///
+    /// - `scope` The group to leave
+    ///
+    pub(crate) fn exit(&mut self, scope: Scope) {
+        self.current = scope.parent;
+        self.selected = scope.inherited;
+        if self.pending.last().eq(&Some(&scope.id)) {
+            // Nothing inside was selected, the group is filtered as a whole
+            self.pending.pop();
+            let inside: usize = self.tree.totals(scope.id).filtered;
+            self.filtered.set(self.filtered.get() - inside);
+            self.tree.prune(scope.id);
+            self.filter_out(Some(scope.id));
+            return;
+        }
+        if let Some(group) = self.tree.get_mut(scope.id) {
+            group.duration = scope.started_at.elapsed();
+        }
+        if let Some(group) = self.tree.get(scope.id).cloned() {
+            self.emit(|r| r.group_exit(&group));
+        }
+    }
+
+    ///
+    /// Run a group or a subgroup between its enter and exit events
+    ///
+    /// - `description` The group title
+    /// - `kind` Group or subgroup
+    /// - `tags` The group tags
+    /// - `it` The callback that runs the group
+    ///
+    fn scope<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        kind: GroupKind,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        if let Some(scope) = self.enter(description, kind, tags) {
+            it(self);
+            self.exit(scope);
+        }
+        self
+    }
 }
 
 impl Testing for Unit {
     fn new() -> Self {
         let i: Instant = Instant::now();
-        title_output("starting tests", SUCCESS);
+        let seed: u64 = soul::seed();
         Self {
             started_at: i,
             asserts: Cell::new(0),
             failures: Cell::new(0),
             skipped: Cell::new(0),
+            filtered: Cell::new(0),
+            filter: Filter::from_env(),
+            tags: Tags::from_env(),
+            skips: Vec::new(),
+            listing: None,
+            selected: false,
+            fail_fast: false,
+            records: Vec::new(),
             sleep_time: DEFAULT_SLEEP_TIME,
+            reporters: reporter::from_env(),
+            started: false,
+            tree: Tree::default(),
+            current: None,
+            pending: Vec::new(),
+            mark: i,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            significance: stats::DEFAULT_SIGNIFICANCE,
         }
     }
 
     fn ok(&mut self, description: &str, data: Vec<bool>) -> &mut Self {
         for t in &data {
-            if check(description, t.clone().eq(&true), self.sleep_time).eq(&true) {
-                self.asserts.set(self.asserts.get() + 1);
-            } else {
-                self.failures.set(self.failures.get() + 1);
-            }
+            self.check(description, t.eq(&true));
         }
         self
     }
 
     fn ko(&mut self, description: &str, data: Vec<bool>) -> &mut Self {
         for t in &data {
-            if check(description, t.clone().eq(&false), self.sleep_time).eq(&true) {
-                self.asserts.set(self.asserts.get() + 1);
-            } else {
-                self.failures.set(self.failures.get() + 1);
-            }
+            self.check(description, t.eq(&false));
         }
         self
     }
 
-    fn eq<T: PartialEq>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self {
-        for test in &data {
-            check(description, test.eq(&expected), self.sleep_time);
-        }
-        self
+    fn eq<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.compare(description, &data, &expected, "", |a, b| a.eq(b))
     }
 
-    fn ne<T: PartialEq>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self {
-        for test in &data {
-            check(description, test.ne(&expected), self.sleep_time);
-        }
-        self
+    fn ne<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.compare(description, &data, &expected, "!= ", |a, b| a.ne(b))
     }
 
-    fn group(&mut self, description: &str, it: fn(&mut Self) -> &mut Self) -> &mut Self {
-        title_output(description, SUCCESS);
-        it(self)
+    fn group<F: FnOnce(&mut Self) -> &mut Self>(&mut self, description: &str, it: F) -> &mut Self {
+        self.scope(description, GroupKind::Group, &[], it)
     }
 
-    fn is<T: PartialEq>(&mut self, description: &str, value: T, expected: T) -> &mut Self {
+    fn is<T: PartialEq + Debug>(&mut self, description: &str, value: T, expected: T) -> &mut Self {
         self.eq(description, vec![value], expected)
     }
 
-    fn not<T: PartialEq>(&mut self, description: &str, value: T, expected: T) -> &mut Self {
+    fn not<T: PartialEq + Debug>(&mut self, description: &str, value: T, expected: T) -> &mut Self {
         self.ne(description, vec![value], expected)
     }
 
-    fn len<T: PartialEq>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self {
+    fn len<T: PartialEq + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
         self.eq(description, data, expected)
     }
 
-    fn gt<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self {
-        for test in &data {
-            check(description, test.gt(&expected), self.sleep_time);
+    fn gt<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.compare(description, &data, &expected, "> ", |a, b| a.gt(b))
+    }
+
+    fn lt<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.compare(description, &data, &expected, "< ", |a, b| a.lt(b))
+    }
+
+    fn ge<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.compare(description, &data, &expected, ">= ", |a, b| a.ge(b))
+    }
+
+    fn empty(&mut self, description: &str, data: String) -> &mut Self {
+        self.check(description, data.is_empty());
+        self
+    }
+
+    fn between<T: PartialOrd>(
+        &mut self,
+        description: &str,
+        min: T,
+        max: T,
+        current: T,
+    ) -> &mut Self {
+        self.check(description, current > min && current < max);
+        self
+    }
+
+    fn le<T: PartialOrd + Debug>(
+        &mut self,
+        description: &str,
+        data: Vec<T>,
+        expected: T,
+    ) -> &mut Self {
+        self.compare(description, &data, &expected, "<= ", |a, b| a.le(b))
+    }
+
+    fn run(&mut self) -> ExitCode {
+        self.finish().exit_code()
+    }
+
+    fn finish(&mut self) -> Report {
+        let report: Report = Report {
+            totals: Counts {
+                passed: self.asserts.get(),
+                failed: self.failures.get(),
+                skipped: self.skipped.get(),
+                filtered: self.filtered.get(),
+            },
+            records: self.records.clone(),
+            tree: self.tree.clone(),
+            elapsed: self.started_at.elapsed(),
+            seed: self.seed,
+        };
+        if let Some(listing) = self.listing.as_ref() {
+            for name in &listing.names {
+                println!("{name}: test");
+            }
+            if !listing.terse {
+                let count: usize = listing.names.len();
+                let plural: &str = if count.eq(&1) { "" } else { "s" };
+                println!("\n{count} test{plural}, 0 benchmarks");
+            }
+            return report;
+        }
+        self.emit(|r| r.suite_end(&report));
+        report
+    }
+
+    fn full(&mut self, description: &str, min: usize, max: usize, current: usize) -> &mut Self {
+        if max.eq(&0) {
+            sleep(Duration::from_millis(self.sleep_time));
+            let assertion: Assertion =
+                Assertion::new(description, Status::Fail, self.mark.elapsed())
+                    .with_message("the maximum is zero, nothing can be full");
+            self.report(assertion);
+            return self;
+        }
+        self.check(description, min.add(current).div_euclid(max).eq(&1));
+        self
+    }
+
+    fn throws<E: Debug, F: FnOnce() -> Result<(), E>>(
+        &mut self,
+        description: &str,
+        f: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
         }
+        let i: Instant = Instant::now();
+        let result: Result<(), E> = f();
+        sleep(Duration::from_millis(self.sleep_time));
+        let assertion: Assertion = match result {
+            Ok(()) => Assertion::new(description, Status::Fail, i.elapsed())
+                .with_message("no error thrown"),
+            Err(e) => Assertion::new(description, Status::Pass, i.elapsed())
+                .with_message(format!("threw: {e:?}").as_str()),
+        };
+        self.report(assertion);
+        self
+    }
+    fn timed<F: FnOnce() -> bool>(&mut self, description: &str, f: F) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        let ok: bool = f();
+        let duration: Duration = i.elapsed();
+        sleep(Duration::from_millis(self.sleep_time));
+        let status: Status = if ok { Status::Pass } else { Status::Fail };
+        let assertion: Assertion = Assertion::new(description, status, duration)
+            .with_message(format!("completed in {} ms", duration.as_millis()).as_str());
+        self.report(assertion);
         self
     }
 
-    fn lt<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self {
-        for test in &data {
-            check(description, test.lt(&expected), self.sleep_time);
+    fn bench<T, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        budget: Option<Duration>,
+        f: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        let measure: Measure = bench::measure(f);
+        sleep(Duration::from_millis(self.sleep_time));
+        let mut message: String = format!(
+            "min {:.1} ns, mean {:.1} ns, median {:.1} ns, p95 {:.1} ns, stddev {:.1} ns, {} samples of {} iterations, {} outliers rejected",
+            measure.min,
+            measure.mean,
+            measure.median,
+            measure.p95,
+            measure.stddev,
+            measure.samples,
+            measure.iterations,
+            measure.outliers
+        );
+        if let Some(budget) = budget {
+            message.push_str(format!(", budget {} ns", budget.as_nanos()).as_str());
         }
+        self.measured(
+            description,
+            budget.is_none_or(|b| measure.median_duration().le(&b)),
+            i,
+            &message,
+        )
+    }
+
+    fn subgroup<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        it: F,
+    ) -> &mut Self {
+        self.scope(description, GroupKind::Subgroup, &[], it)
+    }
+
+    fn tagged_group<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        self.scope(description, GroupKind::Group, tags, it)
+    }
+
+    fn tagged_subgroup<F: FnOnce(&mut Self) -> &mut Self>(
+        &mut self,
+        description: &str,
+        tags: &[&str],
+        it: F,
+    ) -> &mut Self {
+        self.scope(description, GroupKind::Subgroup, tags, it)
+    }
+
+    fn skip(&mut self, description: &str) -> &mut Self {
+        let assertion: Assertion = Assertion::new(description, Status::Skip, Duration::ZERO);
+        self.report(assertion);
+        self
+    }
+    fn take(&mut self) -> Instant {
+        self.started_at
+    }
+
+    fn get_assertions(&mut self) -> Cell<usize> {
+        self.asserts.clone()
+    }
+
+    fn get_failures(&mut self) -> Cell<usize> {
+        self.failures.clone()
+    }
+
+    fn get_skipped(&mut self) -> Cell<usize> {
+        self.skipped.clone()
+    }
+
+    fn get_filtered(&mut self) -> Cell<usize> {
+        self.filtered.clone()
+    }
+
+    fn get_records(&mut self) -> &[Assertion] {
+        &self.records
+    }
+
+    fn get_tree(&mut self) -> &Tree {
+        &self.tree
+    }
+
+    fn get_seed(&mut self) -> u64 {
+        self.seed
+    }
+
+    fn get_rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    fn get_significance(&mut self) -> f64 {
+        self.significance
+    }
+
+    fn set_sleep_time(&mut self, time: u64) -> &mut Self {
+        self.sleep_time = time;
         self
     }
 
-    fn ge<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self {
-        for test in &data {
-            check(description, test.ge(&expected), self.sleep_time);
+    fn set_filter(&mut self, pattern: &str) -> &mut Self {
+        self.filter = Some(Filter::new(pattern));
+        self
+    }
+
+    fn set_tags(&mut self, expression: &str) -> &mut Self {
+        self.tags = Some(Tags::new(expression));
+        self
+    }
+
+    fn set_args(&mut self, args: Vec<String>) -> &mut Self {
+        let mut args = args.into_iter();
+        let mut patterns: Vec<String> = Vec::new();
+        let mut skips: Vec<String> = Vec::new();
+        let mut format: Option<String> = None;
+        let (mut exact, mut list, mut ignored): (bool, bool, bool) = (false, false, false);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--filter" => patterns.extend(args.next()),
+                "--tags" => {
+                    if let Some(expression) = args.next() {
+                        self.set_tags(&expression);
+                    }
+                }
+                "--skip" => skips.extend(args.next()),
+                "--format" => format = args.next(),
+                "-q" | "--quiet" => format = Some(String::from("terse")),
+                "--exact" => exact = true,
+                "--list" => list = true,
+                "--ignored" => ignored = true,
+                "--test-threads" | "--color" | "--logfile" | "--shuffle-seed" | "-Z" => {
+                    args.next();
+                }
+                _ => {
+                    if let Some(pattern) = arg.strip_prefix("--filter=") {
+                        patterns.push(pattern.to_string());
+                    } else if let Some(expression) = arg.strip_prefix("--tags=") {
+                        self.set_tags(expression);
+                    } else if let Some(skip) = arg.strip_prefix("--skip=") {
+                        skips.push(skip.to_string());
+                    } else if let Some(name) = arg.strip_prefix("--format=") {
+                        format = Some(name.to_string());
+                    } else if !arg.starts_with('-') {
+                        patterns.push(arg);
+                    }
+                }
+            }
+        }
+        let filter = |pattern: &str| {
+            if exact {
+                Filter::exact(pattern)
+            } else {
+                Filter::new(pattern)
+            }
+        };
+        if let Some(pattern) = patterns.last() {
+            self.filter = Some(filter(pattern));
+        }
+        self.skips.extend(skips.iter().map(|skip| filter(skip)));
+        if ignored {
+            // No test is ignored, an empty substring skips them all
+            self.skips.push(Filter::new(""));
+        }
+        // The libtest formats soul doesn't emit, like json, fall back to terse
+        let format: Option<&str> = format.as_deref().map(|name| match name {
+            "pretty" | "console" | "tap" => name,
+            _ => "terse",
+        });
+        if let Some(name) = format {
+            self.reporters = reporter::from_format(name);
+        }
+        if list {
+            self.listing = Some(Listing {
+                terse: format.is_some_and(|f| f.eq("terse")),
+                names: Vec::new(),
+            });
         }
         self
     }
 
-    fn empty(&mut self, description: &str, data: String) -> &mut Self {
-        check(description, data.is_empty(), self.sleep_time);
+    fn set_fail_fast(&mut self, fail_fast: bool) -> &mut Self {
+        self.fail_fast = fail_fast;
         self
     }
 
-    fn between<T: PartialOrd>(
+    fn set_significance(&mut self, significance: f64) -> &mut Self {
+        assert!(
+            significance > 0.0 && significance < 1.0,
+            "the significance level must be between 0 and 1 exclusive, got {significance}"
+        );
+        self.significance = significance;
+        self
+    }
+
+    fn set_seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    fn add_reporter<R: Reporter + 'static>(&mut self, reporter: R) -> &mut Self {
+        self.reporters.push(Box::new(reporter));
+        self
+    }
+
+    fn set_reporters(&mut self, reporters: Vec<Box<dyn Reporter>>) -> &mut Self {
+        self.reporters = reporters;
+        self
+    }
+
+    fn always<T: PartialEq, F: FnMut() -> T>(
         &mut self,
         description: &str,
-        min: T,
-        max: T,
-        current: T,
+        iteration: usize,
+        expected: T,
+        mut c: F,
     ) -> &mut Self {
-        check(description, current > min && current < max, self.sleep_time);
+        self.iterate(description, iteration, || c().eq(&expected))
+    }
+
+    fn confirm_contains_in<T: PartialEq, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: Vec<T>,
+        mut c: F,
+    ) -> &mut Self {
+        self.iterate(description, iteration, || expected.contains(&c()).eq(&true))
+    }
+
+    fn confirm_not_contains_in<T: PartialEq, F: FnMut() -> T>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: Vec<T>,
+        mut c: F,
+    ) -> &mut Self {
+        self.iterate(description, iteration, || {
+            expected.contains(&c()).eq(&false)
+        })
+    }
+
+    fn deterministic<T, R, F>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut func: F,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+    {
+        self.generated(description, iteration, |input: T| {
+            let (first, second): (R, R) = (func(input.clone()), func(input.clone()));
+            first
+                .ne(&second)
+                .then(|| format!("input {input:?} gives {first:?} then {second:?}"))
+        })
+    }
+
+    fn sensitive<T, R, F, M>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut func: F,
+        mut mutate: M,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        M: FnMut(&mut T),
+    {
+        self.generated(description, iteration, |original: T| {
+            let mut input: T = original.clone();
+            mutate(&mut input);
+            let (before, after): (R, R) = (func(original.clone()), func(input.clone()));
+            before
+                .eq(&after)
+                .then(|| format!("input {original:?} mutated to {input:?} both give {before:?}"))
+        })
+    }
+
+    fn invariant<T, R, F, E>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut func: F,
+        mut equiv: E,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        E: FnMut(T) -> T,
+    {
+        self.generated(description, iteration, |input: T| {
+            let transformed: T = equiv(input.clone());
+            let (before, after): (R, R) = (func(input.clone()), func(transformed.clone()));
+            before.ne(&after).then(|| {
+                format!(
+                    "input {input:?} gives {before:?}, equivalent {transformed:?} gives {after:?}"
+                )
+            })
+        })
+    }
+
+    fn metamorphic<T, R, F>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut func: F,
+        relations: Vec<Relation<'_, T, R>>,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Clone + Debug,
+        R: Debug,
+        F: FnMut(T) -> R,
+    {
+        for mut relation in relations {
+            let name: String = format!("{description}: {}", relation.name());
+            let mut rng: StdRng = self.generator(format!("{name} follow-ups").as_str());
+            self.generated(&name, iteration, |input: T| {
+                let source: R = func(input.clone());
+                let follow: T = relation.follow(input.clone(), &mut rng);
+                let followup: R = func(follow.clone());
+                (!relation.holds(&source, &followup)).then(|| {
+                    format!(
+                        "input {input:?} gives {source:?}, follow-up {follow:?} gives {followup:?}"
+                    )
+                })
+            });
+        }
         self
     }
 
-    fn le<T: PartialOrd>(&mut self, description: &str, data: Vec<T>, expected: T) -> &mut Self {
-        for test in &data {
-            check(description, test.le(&expected), self.sleep_time);
+    fn differential<T, R, F, G>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        mut reference: F,
+        mut candidate: G,
+    ) -> &mut Self
+    where
+        T: Dummy<Faker> + Shrink + Clone + Debug,
+        R: PartialEq + Debug,
+        F: FnMut(T) -> R,
+        G: FnMut(T) -> R,
+    {
+        self.generated(description, iteration, |input: T| {
+            if reference(input.clone()).eq(&candidate(input.clone())) {
+                return None;
+            }
+            let (minimal, shrinks): (T, usize) = shrink::minimize(input.clone(), |c: &T| {
+                reference(c.clone()).ne(&candidate(c.clone()))
+            });
+            let (expected, actual): (R, R) =
+                (reference(minimal.clone()), candidate(minimal.clone()));
+            let mut detail: String =
+                format!("input {minimal:?} gives {actual:?}, the reference gives {expected:?}");
+            if shrinks.gt(&0) {
+                detail.push_str(format!(", shrunk {shrinks} times from {input:?}").as_str());
+            }
+            Some(detail)
+        })
+    }
+
+    fn eq_laws<T: Dummy<Faker> + Eq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        let triples: Vec<(T, T, T)> = self.triples(description, iteration);
+        self.law(
+            format!("{description}: reflexive").as_str(),
+            &triples,
+            |a, _, _| a.eq(a),
+        )
+        .law(
+            format!("{description}: symmetric").as_str(),
+            &triples,
+            |a, b, _| a.eq(b).eq(&b.eq(a)),
+        )
+        .law(
+            format!("{description}: transitive").as_str(),
+            &triples,
+            |a, b, c| !(a.eq(b) && b.eq(c)) || a.eq(c),
+        )
+        .law(
+            format!("{description}: inequality").as_str(),
+            &triples,
+            |a, b, _| a.ne(b).eq(&!a.eq(b)),
+        )
+    }
+
+    fn ord_laws<T: Dummy<Faker> + Ord + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        let triples: Vec<(T, T, T)> = self.triples(description, iteration);
+        self.law(
+            format!("{description}: antisymmetric").as_str(),
+            &triples,
+            |a, b, _| a.cmp(b).eq(&b.cmp(a).reverse()),
+        )
+        .law(
+            format!("{description}: transitive").as_str(),
+            &triples,
+            |a, b, c| !(a.le(b) && b.le(c)) || a.le(c),
+        )
+        .law(
+            format!("{description}: consistent with eq").as_str(),
+            &triples,
+            |a, b, _| a.cmp(b).is_eq().eq(&a.eq(b)),
+        )
+        .law(
+            format!("{description}: consistent with partial_cmp").as_str(),
+            &triples,
+            |a, b, _| a.partial_cmp(b).eq(&Some(a.cmp(b))),
+        )
+    }
+
+    fn hash_laws<T: Dummy<Faker> + Hash + Eq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        let triples: Vec<(T, T, T)> = self.triples(description, iteration);
+        let hash = |value: &T| {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        self.law(
+            format!("{description}: consistent with eq").as_str(),
+            &triples,
+            |a, b, _| a.ne(b) || hash(a).eq(&hash(b)),
+        )
+    }
+
+    fn clone_laws<T: Dummy<Faker> + PartialEq + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        let triples: Vec<(T, T, T)> = self.triples(description, iteration);
+        self.law(
+            format!("{description}: clone equality").as_str(),
+            &triples,
+            |a, _, _| a.clone().eq(a),
+        )
+    }
+
+    fn laws<T: Dummy<Faker> + Ord + Hash + Clone + Debug>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+    ) -> &mut Self {
+        self.eq_laws::<T>(format!("{description} eq").as_str(), iteration)
+            .ord_laws::<T>(format!("{description} ord").as_str(), iteration)
+            .hash_laws::<T>(format!("{description} hash").as_str(), iteration)
+            .clone_laws::<T>(format!("{description} clone").as_str(), iteration)
+    }
+
+    fn avalanche<F: FnMut(&[u8]) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        length: usize,
+        tolerance: f64,
+        mut hash: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        let bits: usize = length * 8;
+        let mut flipped: Vec<u64> = vec![0; bits];
+        for _ in 0..iteration {
+            let mut input: Vec<u8> = vec![0; length];
+            self.rng.fill(input.as_mut_slice());
+            let original: u64 = hash(&input);
+            for (bit, count) in flipped.iter_mut().enumerate() {
+                input[bit / 8] ^= 1 << (bit % 8);
+                *count += u64::from((original ^ hash(&input)).count_ones());
+                input[bit / 8] ^= 1 << (bit % 8);
+            }
+        }
+        let samples: f64 = (iteration.max(1) * 64) as f64;
+        let rates: Vec<f64> = flipped.iter().map(|c| *c as f64 / samples).collect();
+        let mean: f64 = rates.iter().sum::<f64>() / bits.max(1) as f64;
+        let worst: f64 = rates
+            .iter()
+            .copied()
+            .max_by(|a, b| (a - 0.5).abs().total_cmp(&(b - 0.5).abs()))
+            .unwrap_or(0.0);
+        self.measured(
+            description,
+            iteration.gt(&0) && bits.gt(&0) && (worst - 0.5).abs().le(&tolerance),
+            i,
+            format!(
+                "flips {:.2}% of the output bits, worst input bit {:.2}%, expected 50% ± {:.2}%",
+                mean * 100.0,
+                worst * 100.0,
+                tolerance * 100.0
+            )
+            .as_str(),
+        )
+    }
+
+    fn collisions<T: Dummy<Faker> + Hash + Eq, F: FnMut(&T) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        max_rate: f64,
+        mut hash: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        if !self.sampled(description, i, iteration, 1) {
+            return self;
         }
-        self
-    }
-
-    fn run(&mut self) -> ExitCode {
-        title_output(
-            format!("Tests take {} s", self.started_at.elapsed().as_secs()).as_str(),
-            SUCCESS,
-        );
-        results_output(
-            self.failures.get().eq(&0),
-            "No errors has been fouded",
-            "Errors has been founded",
-            self,
+        let inputs: HashSet<T> = (0..iteration)
+            .map(|_| Faker.fake_with_rng(&mut self.rng))
+            .collect();
+        let hashes: HashSet<u64> = inputs.iter().map(&mut hash).collect();
+        let collisions: usize = inputs.len() - hashes.len();
+        let rate: f64 = collisions as f64 / inputs.len().max(1) as f64;
+        self.measured(
+            description,
+            rate.le(&max_rate),
+            i,
+            format!(
+                "{collisions} collisions in {} distinct inputs, rate {:.4}%, max {:.4}%",
+                inputs.len(),
+                rate * 100.0,
+                max_rate * 100.0
+            )
+            .as_str(),
         )
     }
 
-    fn full(&mut self, description: &str, min: usize, max: usize, current: usize) -> &mut Self {
-        if max.ne(&0) {
-            check(
+    fn buckets<T: Dummy<Faker>, F: FnMut(&T) -> u64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        buckets: usize,
+        mut hash: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        if buckets.lt(&2) {
+            return self.measured(
                 description,
-                min.add(current).div_euclid(max).eq(&1),
-                self.sleep_time,
+                false,
+                i,
+                format!("{buckets} buckets, at least 2 needed").as_str(),
             );
         }
-        self
+        if !self.sampled(description, i, iteration, 1) {
+            return self;
+        }
+        let mut observed: Vec<usize> = vec![0; buckets];
+        for _ in 0..iteration {
+            let input: T = Faker.fake_with_rng(&mut self.rng);
+            observed[(hash(&input) % buckets as u64) as usize] += 1;
+        }
+        let expected: Vec<f64> = vec![iteration as f64 / buckets as f64; buckets];
+        let statistic: f64 = stats::chi_square(&observed, &expected);
+        let p_value: f64 = stats::chi_square_p_value(statistic, buckets - 1);
+        self.significant(
+            description,
+            i,
+            p_value,
+            format!(
+                "chi-square {statistic:.2} with {} degrees of freedom",
+                buckets - 1
+            )
+            .as_str(),
+        )
     }
 
-    fn throws<E: Debug, F: FnOnce() -> Result<(), E>>(
+    fn uniform<T: PartialEq + Debug, F: FnMut(&mut StdRng) -> T>(
         &mut self,
         description: &str,
-        f: F,
+        iteration: usize,
+        categories: Vec<T>,
+        mut sample: F,
     ) -> &mut Self {
-        match f() {
-            Ok(_) => {
-                failure_ouptut(format!("{description} (no error thrown)").as_str());
-            }
-            Err(e) => {
-                success_output(format!("{description} (threw: {:?})", e).as_str());
-            }
+        if !self.admits(description) {
+            return self;
         }
-        self
-    }
-    fn timed<F: FnOnce() -> bool>(&mut self, description: &str, f: F) -> &mut Self {
         let i: Instant = Instant::now();
-        let ok: bool = f();
-        let duration: u128 = i.elapsed().as_millis();
-        check(description, ok, self.sleep_time);
-        success_output(format!("completed in {} ms", duration).as_str());
-        self
-    }
-
-    fn subgroup(&mut self, description: &str, it: fn(&mut Self) -> &mut Self) -> &mut Self {
-        title_output(description, "sub");
-        it(self)
-    }
-
-    fn skip(&mut self, description: &str) -> &mut Self {
-        self.skipped.set(self.skipped.get() + 1);
-        skip_output(description);
-        self
-    }
-    fn take(&mut self) -> Instant {
-        self.started_at
-    }
-
-    fn get_assertions(&mut self) -> Cell<usize> {
-        self.asserts.clone()
-    }
-
-    fn get_failures(&mut self) -> Cell<usize> {
-        self.failures.clone()
+        if !self.sampled(description, i, iteration, 1) {
+            return self;
+        }
+        let mut observed: Vec<usize> = vec![0; categories.len()];
+        let mut outside: Option<T> = None;
+        for _ in 0..iteration {
+            let value: T = sample(&mut self.rng);
+            match categories.iter().position(|c| c.eq(&value)) {
+                Some(index) => observed[index] += 1,
+                None => outside = outside.or(Some(value)),
+            }
+        }
+        if let Some(value) = outside {
+            return self.measured(
+                description,
+                false,
+                i,
+                format!("{value:?} is not one of the categories {categories:?}").as_str(),
+            );
+        }
+        let freedom: usize = categories.len().saturating_sub(1);
+        let expected: Vec<f64> =
+            vec![iteration as f64 / categories.len().max(1) as f64; categories.len()];
+        let statistic: f64 = stats::chi_square(&observed, &expected);
+        let p_value: f64 = stats::chi_square_p_value(statistic, freedom);
+        self.significant(
+            description,
+            i,
+            p_value,
+            format!(
+                "chi-square {statistic:.2} with {freedom} degrees of freedom, counts {observed:?}"
+            )
+            .as_str(),
+        )
     }
 
-    fn get_skipped(&mut self) -> Cell<usize> {
-        self.skipped.clone()
+    fn mean<F: FnMut(&mut StdRng) -> f64>(
+        &mut self,
+        description: &str,
+        iteration: usize,
+        expected: f64,
+        mut sample: F,
+    ) -> &mut Self {
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        if !self.sampled(description, i, iteration, 1) {
+            return self;
+        }
+        let samples: Vec<f64> = (0..iteration).map(|_| sample(&mut self.rng)).collect();
+        let mean: f64 = stats::mean(&samples);
+        let error: f64 = (stats::variance(&samples) / samples.len().max(1) as f64).sqrt();
+        let z: f64 = if error > 0.0 {
+            (mean - expected) / error
+        } else if mean.eq(&expected) {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+        let margin: f64 = stats::normal_critical(self.significance) * error;
+        self.significant(
+            description,
+            i,
+            stats::normal_p_value(z),
+            format!(
+                "mean {mean:.6}, expected {expected}, confidence bounds [{:.6}, {:.6}], z {z:.3}",
+                mean - margin,
+                mean + margin
+            )
+            .as_str(),
+        )
     }
 
-    fn set_sleep_time(&mut self, time: u64) -> &mut Self {
-        self.sleep_time = time;
-        self
-    }
-    fn always<T: PartialEq>(
+    fn variance<F: FnMut(&mut StdRng) -> f64>(
         &mut self,
         description: &str,
         iteration: usize,
-        expected: T,
-        c: fn() -> T,
+        expected: f64,
+        mut sample: F,
     ) -> &mut Self {
-        for _ in 0..iteration {
-            assert!(check(description, c().eq(&expected), self.sleep_time));
+        assert!(
+            expected > 0.0,
+            "the expected variance must be greater than 0, got {expected}"
+        );
+        if !self.admits(description) {
+            return self;
         }
-        self
+        let i: Instant = Instant::now();
+        if !self.sampled(description, i, iteration, 2) {
+            return self;
+        }
+        let samples: Vec<f64> = (0..iteration).map(|_| sample(&mut self.rng)).collect();
+        let variance: f64 = stats::variance(&samples);
+        let freedom: usize = samples.len().saturating_sub(1);
+        let statistic: f64 = freedom as f64 * variance / expected;
+        let upper: f64 = stats::chi_square_p_value(statistic, freedom);
+        let p_value: f64 = (2.0 * upper.min(1.0 - upper)).min(1.0);
+        let half: f64 = self.significance / 2.0;
+        let scaled: f64 = freedom as f64 * variance;
+        let low: f64 = scaled / stats::chi_square_quantile(half, freedom);
+        let high: f64 = scaled / stats::chi_square_quantile(1.0 - half, freedom);
+        self.significant(
+            description,
+            i,
+            p_value,
+            format!(
+                "variance {variance:.6}, expected {expected}, confidence bounds [{low:.6}, {high:.6}], chi-square {statistic:.2} with {freedom} degrees of freedom"
+            )
+            .as_str(),
+        )
     }
 
-    fn confirm_contains_in<T: PartialEq>(
+    fn frequency<F: FnMut(&mut StdRng) -> bool>(
         &mut self,
         description: &str,
         iteration: usize,
-        expected: Vec<T>,
-        c: fn() -> T,
+        expected: f64,
+        tolerance: f64,
+        mut sample: F,
     ) -> &mut Self {
-        for _ in 0..iteration {
-            assert!(check(
-                description,
-                expected.contains(&c()).eq(&true),
-                self.sleep_time
-            ));
+        assert!(
+            (0.0..=1.0).contains(&expected),
+            "the expected frequency must be between 0 and 1, got {expected}"
+        );
+        if !self.admits(description) {
+            return self;
         }
-        self
+        let i: Instant = Instant::now();
+        if !self.sampled(description, i, iteration, 1) {
+            return self;
+        }
+        let hits: usize = (0..iteration).filter(|_| sample(&mut self.rng)).count();
+        let frequency: f64 = hits as f64 / iteration.max(1) as f64;
+        let distance: f64 = ((frequency - expected).abs() - tolerance).max(0.0);
+        let error: f64 = (expected * (1.0 - expected) / iteration.max(1) as f64).sqrt();
+        let z: f64 = if error > 0.0 {
+            distance / error
+        } else if distance.eq(&0.0) {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+        self.significant(
+            description,
+            i,
+            stats::normal_p_value(z),
+            format!(
+                "frequency {frequency:.6} ({hits}/{iteration}), expected {expected} ± {tolerance}, z {z:.3}"
+            )
+            .as_str(),
+        )
     }
 
-    fn confirm_not_contains_in<T: PartialEq>(
+    fn property<T: Dummy<Faker> + Shrink + Clone + Debug, F: FnMut(T) -> bool>(
         &mut self,
         description: &str,
         iteration: usize,
-        expected: Vec<T>,
-        c: fn() -> T,
+        mut property: F,
     ) -> &mut Self {
-        for _ in 0..iteration {
-            assert!(check(
-                description,
-                expected.contains(&c()).eq(&false),
-                self.sleep_time
-            ));
+        if !self.admits(description) {
+            return self;
+        }
+        let i: Instant = Instant::now();
+        let mut failure: Option<(usize, T)> = None;
+        for index in 0..iteration {
+            let input: T = Faker.fake_with_rng(&mut self.rng);
+            if !property(input.clone()) {
+                failure = Some((index, input));
+                break;
+            }
+        }
+        let Some((index, input)) = failure else {
+            self.report(Assertion::new(description, Status::Pass, i.elapsed()));
+            return self;
+        };
+        let (minimal, shrinks): (T, usize) =
+            shrink::minimize(input.clone(), |candidate| !property(candidate.clone()));
+        let mut assertion: Assertion = Assertion::new(description, Status::Fail, i.elapsed())
+            .with_message(
+                format!(
+                    "falsified by {minimal:?} after {} of {iteration} inputs, shrunk {shrinks} times from {input:?}",
+                    index + 1
+                )
+                .as_str(),
+            );
+        assertion.index = Some(index);
+        self.report(assertion);
+        if self.fail_fast {
+            panic!("{description}: falsified by {minimal:?}");
         }
         self
     }
@@ -253,8 +1678,61 @@ impl Testing for Unit {
 
 #[cfg(test)]
 mod test {
-    use crate::anima::{soul::Testing, unit::Unit};
-    use std::{env::consts::OS, process::ExitCode};
+    use crate::anima::reporter::Status;
+    use crate::anima::{
+        metamorphic::Relation,
+        report::Report,
+        reporter::{Assertion, Reporter},
+        soul::{self, Testing},
+        tree::Group,
+        unit::Unit,
+    };
+    use fake::Dummy;
+    use rand::Rng;
+    use std::hash::{DefaultHasher, Hash, Hasher};
+    use std::panic::{self, AssertUnwindSafe};
+    use std::thread::sleep;
+    use std::time::Duration;
+    use std::{cell::RefCell, env::consts::OS, process::ExitCode, rc::Rc};
+
+    struct Events(Rc<RefCell<Vec<String>>>);
+
+    impl Reporter for Events {
+        fn suite_start(&mut self) {
+            self.0.borrow_mut().push("start".to_string());
+        }
+        fn group_enter(&mut self, group: &Group) {
+            self.0
+                .borrow_mut()
+                .push(format!("enter {}", group.description));
+        }
+        fn group_exit(&mut self, group: &Group) {
+            self.0
+                .borrow_mut()
+                .push(format!("exit {}", group.description));
+        }
+        fn pass(&mut self, assertion: &Assertion) {
+            self.0
+                .borrow_mut()
+                .push(format!("pass {}", assertion.description));
+        }
+        fn fail(&mut self, assertion: &Assertion) {
+            self.0
+                .borrow_mut()
+                .push(format!("fail {}", assertion.description));
+        }
+        fn skip(&mut self, assertion: &Assertion) {
+            self.0
+                .borrow_mut()
+                .push(format!("skip {}", assertion.description));
+        }
+        fn suite_end(&mut self, report: &Report) {
+            self.0
+                .borrow_mut()
+                .push(format!("end {}", report.totals.failed));
+        }
+    }
+
     fn is_thales_verified(ab: f64, ad: f64, ac: f64, ae: f64) -> bool {
         (ab / ad - ac / ae).abs() < f64::EPSILON
     }
@@ -354,4 +1832,755 @@ mod test {
             .full("battery must be full", 0, 100, 100)
             .run()
     }
+
+    #[test]
+    pub fn reporters() {
+        let events: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+        Unit::new()
+            .set_reporters(vec![Box::new(Events(events.clone()))])
+            .group("numbers", |u| {
+                u.ok("one is one", vec![1 == 1])
+                    .subgroup("negative", |u| u.ko("minus one is one", vec![-1 == 1]))
+                    .skip("later")
+            })
+            .run();
+        assert_eq!(
+            events.borrow().as_slice(),
+            [
+                "start",
+                "enter numbers",
+                "pass one is one",
+                "enter negative",
+                "pass minus one is one",
+                "exit negative",
+                "skip later",
+                "exit numbers",
+                "end 0",
+            ]
+        );
+    }
+
+    #[test]
+    pub fn accounting() {
+        let mut unit: Unit = Unit::new();
+        let code: ExitCode = unit
+            .set_reporters(Vec::new())
+            .eq("one is two", vec![1, 1], 2)
+            .gt("one is greater than two", vec![1], 2)
+            .between("five is between one and ten", 1, 10, 5)
+            .throws("parse fails", || "x".parse::<i32>().map(|_| ()))
+            .timed("fast", || true)
+            .full("no capacity", 0, 0, 0)
+            .skip("later")
+            .run();
+        assert_eq!(code, ExitCode::FAILURE);
+        assert_eq!(unit.get_assertions().get(), 3);
+        assert_eq!(unit.get_failures().get(), 4);
+        assert_eq!(unit.get_skipped().get(), 1);
+        assert_eq!(unit.get_records().len(), 8);
+        assert_eq!(
+            unit.get_records()[6].message.as_deref(),
+            Some("the maximum is zero, nothing can be full")
+        );
+    }
+
+    #[test]
+    pub fn values() {
+        let mut unit: Unit = Unit::new();
+        unit.set_reporters(Vec::new())
+            .eq("equal", vec![1, 5, 1], 1)
+            .gt("greater", vec![3, 1, 4], 2)
+            .le("lower", vec![1, 9, 2], 2);
+        let failures: Vec<(Option<usize>, Option<&str>, Option<&str>)> = unit
+            .get_records()
+            .iter()
+            .filter(|r| r.status.eq(&Status::Fail))
+            .map(|r| (r.index, r.expected.as_deref(), r.actual.as_deref()))
+            .collect();
+        assert_eq!(
+            failures,
+            [
+                (Some(1), Some("1"), Some("5")),
+                (Some(1), Some("> 2"), Some("1")),
+                (Some(1), Some("<= 2"), Some("9")),
+            ]
+        );
+        assert_eq!(unit.get_assertions().get(), 6);
+    }
+
+    #[test]
+    pub fn iterations() {
+        let mut unit: Unit = Unit::new();
+        unit.set_reporters(Vec::new())
+            .always("never true", 3, true, || false)
+            .confirm_contains_in("always one", 2, vec![1], || 1);
+        assert_eq!(unit.get_failures().get(), 3);
+        assert_eq!(unit.get_assertions().get(), 2);
+        assert_eq!(
+            unit.get_records()[2].message.as_deref(),
+            Some("iteration 3 of 3 failed, 0/3 passed")
+        );
+    }
+
+    #[test]
+    pub fn fail_fast_message() {
+        let mut unit: Unit = Unit::new();
+        let mut calls: usize = 0;
+        let stopped = panic::catch_unwind(AssertUnwindSafe(|| {
+            unit.set_reporters(Vec::new()).set_fail_fast(true).always(
+                "second fails",
+                5,
+                true,
+                || {
+                    calls += 1;
+                    calls.ne(&2)
+                },
+            );
+        }));
+        assert!(stopped.is_err());
+        assert_eq!(
+            unit.get_records()[1].message.as_deref(),
+            Some("iteration 2 of 5 failed, 1/2 passed")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "never true: iteration 1 of 3 failed")]
+    pub fn fail_fast() {
+        Unit::new()
+            .set_reporters(Vec::new())
+            .set_fail_fast(true)
+            .always("never true", 3, true, || false);
+    }
+
+    #[test]
+    pub fn captures() -> ExitCode {
+        let primes: Vec<u32> = vec![2, 3, 5, 7];
+        let mut calls: usize = 0;
+        Unit::new()
+            .group("primes", |u| {
+                u.eq("first prime", vec![primes[0]], 2)
+                    .subgroup("odd primes", |u| {
+                        u.gt("odd primes are greater than two", primes[1..].to_vec(), 2)
+                    })
+                    .always("counting calls", 4, true, || {
+                        calls += 1;
+                        calls <= 4
+                    })
+            })
+            .run()
+    }
+
+    #[test]
+    pub fn tree() {
+        let mut unit: Unit = Unit::new();
+        unit.set_reporters(Vec::new())
+            .group("parser", |u| {
+                u.ok("parser exists", vec![true]).subgroup("numbers", |u| {
+                    u.subgroup("overflow", |u| {
+                        u.ok("overflow is detected", vec![true, false])
+                            .skip("big numbers")
+                    })
+                })
+            })
+            .ok("outside of any group", vec![true]);
+        let tree = unit.get_tree();
+        let overflow = tree
+            .find("parser > numbers > overflow")
+            .map(|g| (g.id, g.counts));
+        assert_eq!(tree.groups().len(), 3);
+        assert_eq!(tree.roots().count(), 1);
+        assert_eq!(
+            overflow.map(|(_, c)| (c.passed, c.failed, c.skipped)),
+            Some((1, 1, 1))
+        );
+        let totals = tree.totals(0);
+        assert_eq!((totals.passed, totals.failed, totals.skipped), (2, 1, 1));
+        assert_eq!(tree.get(2).and_then(|g| g.parent), Some(1));
+        assert_eq!(unit.get_records()[1].group, overflow.map(|(id, _)| id));
+    }
+
+    #[test]
+    pub fn report() -> Report {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .group("parser", |u| {
+                u.eq("two is two", vec![2], 2)
+                    .eq("one is two", vec![1], 2)
+                    .skip("not implemented")
+            })
+            .finish();
+        assert!(!report.success());
+        assert_eq!(report.totals.total(), 3);
+        assert_eq!(report.skipped().collect::<Vec<&str>>(), ["not implemented"]);
+        assert_eq!(
+            report
+                .failures()
+                .map(|f| f.actual.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("1")]
+        );
+        assert_eq!(report.records("parser").count(), 3);
+        assert_eq!(report.totals("parser").map(|c| c.failed), Some(1));
+        Unit::new()
+            .set_reporters(Vec::new())
+            .ok("the report is a termination", vec![true])
+            .finish()
+    }
+
+    #[test]
+    pub fn filter() {
+        let mut runs: Vec<&str> = Vec::new();
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_args(vec!["--filter".to_string(), "parser > numbers".to_string()])
+            .group("lexer", |u| {
+                runs.push("lexer");
+                u.ok("tokens", vec![true])
+            })
+            .group("parser", |u| {
+                runs.push("parser");
+                u.ok("parser exists", vec![true])
+                    .subgroup("numbers", |u| u.ok("overflow", vec![true, true]))
+                    .subgroup("strings", |u| u.ok("escapes", vec![true]))
+            })
+            .finish();
+        assert_eq!(runs, ["parser"]);
+        assert_eq!(report.totals.passed, 2);
+        assert_eq!(report.totals.filtered, 3);
+        assert!(report.group("lexer").is_some_and(|g| g.filtered));
+        assert_eq!(report.totals("parser").map(|c| c.filtered), Some(2));
+    }
+
+    #[test]
+    pub fn nested() {
+        let events: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+        let report: Report = Unit::new()
+            .set_reporters(vec![Box::new(Events(events.clone()))])
+            .set_filter("parser > numbers > detects")
+            .group("lexer", |u| {
+                u.ok("tokens", vec![true])
+                    .subgroup("spaces", |u| u.ok("tabs", vec![true]))
+            })
+            .group("parser", |u| {
+                u.ok("parser exists", vec![true])
+                    .subgroup("numbers", |u| {
+                        u.ok("detects overflow", vec![true])
+                            .ok("reads digits", vec![true])
+                    })
+                    .subgroup("strings", |u| u.ok("escapes", vec![true]))
+            })
+            .finish();
+        assert_eq!(report.totals.passed, 1);
+        assert_eq!(report.totals.filtered, 4);
+        assert!(report.group("lexer").is_some_and(|g| g.filtered));
+        assert!(report.group("lexer > spaces").is_none());
+        assert!(report.group("parser > strings").is_some_and(|g| g.filtered));
+        assert_eq!(report.totals("parser").map(|c| c.filtered), Some(3));
+        assert_eq!(
+            events.borrow().as_slice(),
+            [
+                "start",
+                "enter parser",
+                "enter numbers",
+                "pass detects overflow",
+                "exit numbers",
+                "exit parser",
+                "end 0",
+            ]
+        );
+    }
+
+    #[test]
+    pub fn unselected() {
+        let mut runs: usize = 0;
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_filter("selected")
+            .timed("timed", || {
+                runs += 1;
+                true
+            })
+            .throws("throws", || {
+                runs += 1;
+                Err("thrown")
+            })
+            .always("always", 2, true, || {
+                runs += 1;
+                true
+            })
+            .confirm_contains_in("contains", 2, vec![1], || {
+                runs += 1;
+                1
+            })
+            .confirm_not_contains_in("not contains", 2, vec![1], || {
+                runs += 1;
+                2
+            })
+            .throws("selected throws", || Err("thrown"))
+            .finish();
+        assert_eq!(runs, 0);
+        assert_eq!(report.totals.filtered, 5);
+        assert_eq!(report.totals.passed, 1);
+        assert_eq!(
+            report.records[0].message.as_deref(),
+            Some("threw: \"thrown\"")
+        );
+    }
+
+    #[test]
+    pub fn tags() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_tags("!slow")
+            .tagged_group("database", &["db"], |u| {
+                u.ok("connects", vec![true])
+                    .tagged_subgroup("migrations", &["slow"], |u| u.ok("applies", vec![true]))
+            })
+            .tagged_group("cache", &["slow"], |u| u.ok("expires", vec![true]))
+            .finish();
+        assert_eq!(report.totals.passed, 1);
+        assert_eq!(report.totals.filtered, 2);
+        assert_eq!(
+            report
+                .group("database > migrations")
+                .map(|g| g.tags.clone()),
+            Some(vec!["db".to_string(), "slow".to_string()])
+        );
+        let tags = report.tags();
+        assert_eq!(tags.get("db").map(|c| (c.passed, c.filtered)), Some((1, 1)));
+        assert_eq!(tags.get("slow").map(|c| c.filtered), Some(2));
+    }
+
+    #[test]
+    pub fn libtest() {
+        let args = |a: &[&str]| a.iter().map(|a| (*a).to_string()).collect::<Vec<String>>();
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_args(args(&[
+                "parser",
+                "--exact",
+                "--skip",
+                "parser > strings",
+                "--nocapture",
+            ]))
+            .group("parser", |u| {
+                u.ok("parser exists", vec![true])
+                    .subgroup("strings", |u| u.ok("escapes", vec![true]))
+            })
+            .group("parser v2", |u| u.ok("parses", vec![true]))
+            .finish();
+        assert_eq!(report.totals.passed, 1);
+        assert_eq!(report.totals.filtered, 2);
+        let mut runs: usize = 0;
+        let report: Report = Unit::new()
+            .set_args(args(&["--list", "--format", "terse"]))
+            .group("lexer", |u| {
+                runs += 1;
+                u.ok("tokens", vec![true])
+            })
+            .ok("outside", vec![false, false])
+            .ok("skipped outside", vec![true])
+            .finish();
+        assert_eq!(runs, 0);
+        assert!(report.success());
+        assert_eq!(report.totals.total(), 0);
+        let mut unit: Unit = Unit::new();
+        unit.set_args(args(&["--list", "--skip", "skipped"]))
+            .group("lexer", |u| u.ok("tokens", vec![true]))
+            .ok("outside", vec![false, false])
+            .ok("skipped outside", vec![true]);
+        assert_eq!(
+            unit.listing.as_ref().map(|l| l.names.as_slice()),
+            Some(["lexer".to_string(), "outside".to_string()].as_slice())
+        );
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_args(args(&["outside", "--exact"]))
+            .group("lexer", |u| u.ok("outside", vec![true]))
+            .ok("outside", vec![true])
+            .ok("outside too", vec![true])
+            .finish();
+        assert_eq!(report.totals.passed, 1);
+        assert_eq!(report.totals.filtered, 2);
+    }
+
+    #[test]
+    pub fn libtest_json() {
+        let report: Report = Unit::new()
+            .set_args(vec!["--format".to_string(), "json".to_string()])
+            .set_reporters(Vec::new())
+            .ok("still runs", vec![true])
+            .finish();
+        assert_eq!(report.totals.passed, 1);
+    }
+
+    #[test]
+    pub fn property() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .property("reverse twice", 50, |v: Vec<u8>| {
+                let mut twice: Vec<u8> = v.clone();
+                twice.reverse();
+                twice.reverse();
+                twice.eq(&v)
+            })
+            .property("small numbers", 50, |x: u32| x < 1000)
+            .finish();
+        assert_eq!(report.totals.passed, 1);
+        let message: Option<String> = report.failures().next().and_then(|f| f.message.clone());
+        assert!(message.is_some_and(|m| m.starts_with("falsified by 1000 after")));
+    }
+
+    #[test]
+    pub fn seed() {
+        let inputs = |seed: u64| {
+            let mut inputs: Vec<u64> = Vec::new();
+            let report: Report = Unit::new()
+                .set_reporters(Vec::new())
+                .set_seed(seed)
+                .property("collects", 5, |x: u64| {
+                    inputs.push(x);
+                    true
+                })
+                .finish();
+            assert_eq!(report.seed, seed);
+            inputs
+        };
+        assert_eq!(inputs(42), inputs(42));
+        assert_ne!(inputs(42), inputs(43));
+    }
+
+    #[test]
+    pub fn replay() {
+        let inputs = |filter: &str| {
+            let mut inputs: Vec<u64> = Vec::new();
+            Unit::new()
+                .set_reporters(Vec::new())
+                .set_seed(99)
+                .set_filter(filter)
+                .group("numbers", |u| {
+                    u.property("first", 5, |_: u64| true)
+                        .property("second", 5, |x: u64| {
+                            inputs.push(x);
+                            true
+                        })
+                })
+                .finish();
+            inputs
+        };
+        assert_eq!(inputs("numbers"), inputs("numbers > second"));
+        assert_eq!(soul::parse_seed(Some(" 42 ")), 42);
+        assert!(panic::catch_unwind(|| soul::parse_seed(Some("forty-two"))).is_err());
+    }
+
+    #[test]
+    pub fn helpers() {
+        let inputs = |seed: u64| {
+            let mut inputs: Vec<u64> = Vec::new();
+            let mut unit: Unit = Unit::new();
+            unit.set_seed(seed);
+            assert!(soul::assert_deterministic_with_rng(
+                unit.get_rng(),
+                5,
+                |x: u64| {
+                    inputs.push(x);
+                    x
+                }
+            ));
+            soul::assert_sensitive_with_rng(unit.get_rng(), 5, |x: u64| x, |x| *x ^= 1);
+            soul::assert_invariant_with_rng(
+                unit.get_rng(),
+                5,
+                |x: u8| x.count_ones(),
+                u8::reverse_bits,
+            );
+            inputs
+        };
+        assert_eq!(inputs(7), inputs(7));
+        assert_ne!(inputs(7), inputs(8));
+        assert!(soul::assert_deterministic(5, |x: u64| x));
+        soul::assert_sensitive(5, |x: u64| x, |x| *x ^= 1);
+        soul::assert_invariant(5, |x: u8| x.count_ones(), u8::reverse_bits);
+    }
+
+    #[test]
+    pub fn generated() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .deterministic("length", 10, |s: String| s.len())
+            .sensitive(
+                "parity",
+                10,
+                |x: u8| x % 2,
+                |x: &mut u8| *x = x.wrapping_add(2),
+            )
+            .invariant(
+                "absolute",
+                10,
+                |x: i32| x.unsigned_abs(),
+                |x: i32| x.wrapping_neg(),
+            )
+            .finish();
+        assert_eq!(report.totals.passed, 20);
+        assert_eq!(report.totals.failed, 10);
+        assert!(report.failures().all(|f| {
+            f.message
+                .as_ref()
+                .is_some_and(|m| m.contains(" mutated to ") && m.contains(" both give "))
+        }));
+    }
+
+    #[test]
+    pub fn metamorphic() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .metamorphic(
+                "sum",
+                10,
+                |v: Vec<i16>| v.iter().map(|x| i64::from(*x)).sum::<i64>(),
+                vec![
+                    Relation::equal("permutation").shuffled(),
+                    Relation::maps("doubled", |sum: &i64| sum * 2)
+                        .then(|v: Vec<i16>| [v.clone(), v].concat()),
+                    Relation::increasing("appended").then(|mut v: Vec<i16>| {
+                        v.push(-1);
+                        v
+                    }),
+                ],
+            )
+            .finish();
+        assert_eq!(report.totals.passed, 20);
+        assert_eq!(report.totals.failed, 10);
+        assert!(report.failures().all(|f| f.description.eq("sum: appended")));
+    }
+
+    #[test]
+    pub fn differential() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_seed(21)
+            .differential(
+                "abs",
+                10,
+                |x: i32| x.unsigned_abs(),
+                |x: i32| {
+                    if x < 0 {
+                        x.wrapping_neg().cast_unsigned()
+                    } else {
+                        x.cast_unsigned()
+                    }
+                },
+            )
+            .differential("saturated", 10, |x: u16| x / 2, |x: u16| x.min(1000) / 2)
+            .finish();
+        assert_eq!((report.totals.passed, report.totals.failed), (10, 10));
+        assert!(report.failures().all(|f| {
+            f.message
+                .as_ref()
+                .is_some_and(|m| m.contains("input 1002 gives 500, the reference gives 501"))
+        }));
+    }
+
+    #[derive(Debug, Clone, Dummy)]
+    struct Inverted(u8);
+
+    impl PartialEq for Inverted {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.ne(&other.0)
+        }
+    }
+
+    #[test]
+    pub fn laws() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .eq_laws::<u32>("u32", 20)
+            .ord_laws::<String>("string", 20)
+            .hash_laws::<Vec<i8>>("vec", 20)
+            .clone_laws::<Option<char>>("option", 20)
+            .clone_laws::<Inverted>("inverted", 20)
+            .finish();
+        assert_eq!(report.totals.passed, 10);
+        assert_eq!(report.totals.failed, 1);
+        assert_eq!(
+            report.failures().next().map(|f| f.description.as_str()),
+            Some("inverted: clone equality")
+        );
+    }
+
+    #[test]
+    pub fn every_law() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .laws::<i16>("i16", 20)
+            .finish();
+        assert_eq!(report.totals.passed, 10);
+        assert_eq!(report.totals.failed, 0);
+        assert!(
+            report
+                .records
+                .iter()
+                .any(|r| r.description.eq("i16 ord: transitive"))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "inverted: clone equality: broken by")]
+    pub fn law_fail_fast() {
+        Unit::new()
+            .set_reporters(Vec::new())
+            .set_fail_fast(true)
+            .clone_laws::<Inverted>("inverted", 20)
+            .eq("never reached", vec![1], 1);
+    }
+
+    #[test]
+    pub fn hashes() {
+        let std_hash = |bytes: &[u8]| {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            bytes.hash(&mut hasher);
+            hasher.finish()
+        };
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_seed(7)
+            .avalanche("siphash avalanche", 50, 16, 0.05, std_hash)
+            .avalanche("empty avalanche", 50, 0, 0.05, std_hash)
+            .avalanche("sum avalanche", 50, 16, 0.05, |bytes: &[u8]| {
+                bytes.iter().map(|b| u64::from(*b)).sum()
+            })
+            .collisions("siphash collisions", 1000, 0.0, |s: &String| {
+                std_hash(s.as_bytes())
+            })
+            .collisions("length collisions", 1000, 0.01, |s: &String| s.len() as u64)
+            .buckets("siphash buckets", 2000, 16, |x: &u64| {
+                std_hash(&x.to_le_bytes())
+            })
+            .buckets("even buckets", 2000, 16, |x: &u64| x & !1)
+            .buckets("single bucket", 2000, 1, |x: &u64| *x)
+            .buckets("no bucket input", 0, 16, |x: &u64| *x)
+            .collisions("no collision input", 0, 0.0, |s: &String| s.len() as u64)
+            .finish();
+        let failed: Vec<&str> = report.failures().map(|f| f.description.as_str()).collect();
+        assert_eq!(
+            failed,
+            [
+                "empty avalanche",
+                "sum avalanche",
+                "length collisions",
+                "even buckets",
+                "single bucket",
+                "no bucket input",
+                "no collision input"
+            ]
+        );
+        assert!(report.records.iter().all(|r| r.message.is_some()));
+    }
+
+    #[test]
+    pub fn statistics() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .set_seed(7)
+            .set_significance(0.001)
+            .uniform("fair die", 6000, (1..=6).collect(), |rng| {
+                rng.random_range(1..=6)
+            })
+            .uniform("loaded die", 6000, (1..=6).collect(), |rng| {
+                rng.random_range(1..=7).min(6)
+            })
+            .uniform("seven", 10, (1..=6).collect(), |_| 7)
+            .mean("unit mean", 5000, 0.5, |rng| rng.random::<f64>())
+            .mean("shifted mean", 5000, 0.6, |rng| rng.random::<f64>())
+            .variance("unit variance", 5000, 1.0 / 12.0, |rng| rng.random::<f64>())
+            .variance("wide variance", 5000, 1.0, |rng| rng.random::<f64>())
+            .frequency("coin", 5000, 0.5, 0.0, |rng| rng.random_bool(0.5))
+            .frequency("biased coin", 5000, 0.5, 0.01, |rng| rng.random_bool(0.6))
+            .frequency("tolerated coin", 5000, 0.5, 0.1, |rng| {
+                rng.random_bool(0.55)
+            })
+            .uniform("no die", 0, (1..=6).collect(), |rng| {
+                rng.random_range(1..=6)
+            })
+            .variance("single variance", 1, 1.0, |rng| rng.random::<f64>())
+            .finish();
+        let failed: Vec<&str> = report.failures().map(|f| f.description.as_str()).collect();
+        assert_eq!(
+            failed,
+            [
+                "loaded die",
+                "seven",
+                "shifted mean",
+                "wide variance",
+                "biased coin",
+                "no die",
+                "single variance"
+            ]
+        );
+        assert!(
+            report
+                .records
+                .iter()
+                .filter(|r| r.status.eq(&Status::Pass))
+                .all(|r| r.message.as_ref().is_some_and(|m| m.contains("p-value")))
+        );
+        assert!(
+            report
+                .records
+                .iter()
+                .filter(|r| r.description.ends_with(" variance") && r.status.eq(&Status::Pass))
+                .all(|r| r
+                    .message
+                    .as_ref()
+                    .is_some_and(|m| m.contains("confidence bounds")))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the expected frequency must be between 0 and 1, got 1.5")]
+    pub fn invalid_frequency() {
+        Unit::new()
+            .set_reporters(Vec::new())
+            .frequency("coin", 10, 1.5, 0.0, |rng| rng.random_bool(0.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "the expected variance must be greater than 0, got 0")]
+    pub fn invalid_variance() {
+        Unit::new()
+            .set_reporters(Vec::new())
+            .variance("constant", 10, 0.0, |_| 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the significance level must be between 0 and 1 exclusive")]
+    pub fn invalid_significance() {
+        Unit::new().set_significance(f64::NAN);
+    }
+
+    #[test]
+    pub fn bench() {
+        let report: Report = Unit::new()
+            .set_reporters(Vec::new())
+            .bench("sum", None, || (0..100_u64).sum::<u64>())
+            .bench("sum within budget", Some(Duration::from_secs(1)), || {
+                (0..100_u64).sum::<u64>()
+            })
+            .bench("sleep over budget", Some(Duration::from_micros(10)), || {
+                sleep(Duration::from_micros(100));
+            })
+            .finish();
+        assert_eq!(report.totals.passed, 2);
+        let failure: Option<&Assertion> = report.failures().next();
+        assert_eq!(
+            failure.map(|f| f.description.as_str()),
+            Some("sleep over budget")
+        );
+        assert!(
+            failure
+                .and_then(|f| f.message.as_ref())
+                .is_some_and(|m| m.contains(" median ") && m.ends_with(", budget 10000 ns"))
+        );
+    }
 }
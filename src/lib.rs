@@ -4,4 +4,5 @@ pub mod anima;
 #[doc = "All utilities"]
 pub mod utils;
 
+#[allow(clippy::new_without_default)]
 pub mod soul;
\ No newline at end of file